@@ -0,0 +1,108 @@
+//! Compares keeping a horde of enemies apart by checking every pair of enemies against looking
+//! up neighbours in the spatial hash.
+//!
+//! cargo run --release --example enemy_separation_benchmark
+use std::time::{Duration, Instant};
+
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use fuzzy_broccoli::enemy::{
+    components::Enemy,
+    spatial::{resources::SpatialHash, systems::rebuild_spatial_hash_system},
+    systems::enemy_separation_system,
+};
+
+const FRAMES: usize = 100;
+const ENEMY_COUNTS: [usize; 4] = [250, 1000, 2000, 4000];
+/// Average distance between enemies in the horde, they start out packed closely together.
+const SPACING: f32 = 18.0;
+const MIN_DISTANCE: f32 = 24.0;
+
+/// How overlapping enemies were kept apart before the spatial hash: every pair is compared and
+/// the transforms are nudged directly.
+fn pairwise_overlap_system(
+    mut query: Query<(&mut Transform, &LinearVelocity, Entity), With<Enemy>>,
+) {
+    let enemies: Vec<(Entity, Vec3, Vec2)> = query
+        .iter()
+        .map(|(transform, velocity, entity)| (entity, transform.translation, velocity.0))
+        .collect();
+
+    for i in 0..enemies.len() {
+        for j in (i + 1)..enemies.len() {
+            let (entity_a, pos_a, vel_a) = enemies[i];
+            let (entity_b, pos_b, vel_b) = enemies[j];
+
+            let distance = pos_a.distance(pos_b);
+            if distance < MIN_DISTANCE {
+                let direction = (pos_b - pos_a).normalize_or_zero();
+                let approaching = (vel_b - vel_a).dot(direction.truncate()) < 0.0;
+                if approaching {
+                    let separation = direction * ((MIN_DISTANCE - distance) * 0.5);
+                    if let Ok((mut transform_a, _, _)) = query.get_mut(entity_a) {
+                        transform_a.translation -= separation * 0.7;
+                    }
+                    if let Ok((mut transform_b, _, _)) = query.get_mut(entity_b) {
+                        transform_b.translation += separation * 0.7;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn spawn_horde(world: &mut World, count: usize) {
+    let side = (count as f32).sqrt().ceil() as usize;
+    for index in 0..count {
+        let (column, row) = (index % side, index / side);
+        // Slightly irregular so not every enemy has the same neighbours.
+        let jitter = Vec2::from_angle(index as f32 * 2.4) * SPACING * 0.4;
+        let position = Vec2::new(column as f32, row as f32) * SPACING + jitter;
+        world.spawn((
+            Enemy,
+            Transform::from_translation(position.extend(0.0)),
+            LinearVelocity(-position.normalize_or_zero() * 50.0),
+        ));
+    }
+}
+
+fn benchmark_app(count: usize, hashed: bool) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    spawn_horde(app.world_mut(), count);
+    if hashed {
+        app.init_resource::<SpatialHash>().add_systems(
+            Update,
+            (rebuild_spatial_hash_system, enemy_separation_system).chain(),
+        );
+    } else {
+        app.add_systems(Update, pairwise_overlap_system);
+    }
+    app.update();
+    app
+}
+
+fn run(count: usize, hashed: bool) -> Duration {
+    let mut app = benchmark_app(count, hashed);
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        app.update();
+    }
+    start.elapsed()
+}
+
+pub fn main() {
+    println!("{} frames per run, enemies {} apart", FRAMES, SPACING);
+    let per_frame = |duration: Duration| duration.as_secs_f64() * 1000.0 / FRAMES as f64;
+    for count in ENEMY_COUNTS {
+        let pairwise = run(count, false);
+        let hashed = run(count, true);
+        println!(
+            "{:>5} enemies: pairwise {:>8.3} ms per frame, spatial hash {:>7.3} ms per frame ({:.1}x)",
+            count,
+            per_frame(pairwise),
+            per_frame(hashed),
+            pairwise.as_secs_f64() / hashed.as_secs_f64()
+        );
+    }
+}
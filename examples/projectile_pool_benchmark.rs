@@ -0,0 +1,173 @@
+//! Compares firing projectiles by spawning and despawning fresh entities against recycling
+//! them through the projectile pool.
+//!
+//! cargo run --release --example projectile_pool_benchmark
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use fuzzy_broccoli::{
+    collision::GameLayer,
+    player::weapon::{
+        components::{
+            DamageFalloff, ImpactEffect, Pierce, Projectile, ProjectileHits, ProjectileLifetime,
+            ProjectileRange, Ricochet,
+        },
+        pool::{
+            launch_projectile, prewarm_pools_system, release_projectile, EffectPool,
+            ProjectilePool,
+        },
+    },
+};
+
+const FRAMES: usize = 600;
+const SHOTS_PER_FRAME: usize = 40;
+const LIFETIME_FRAMES: usize = 30;
+
+/// Projectiles fired in each of the last `LIFETIME_FRAMES` frames.
+#[derive(Resource, Default)]
+struct InFlight(VecDeque<Vec<Entity>>);
+
+fn gameplay_components() -> impl Bundle {
+    (
+        Projectile {
+            damage: 20.0,
+            critical: false,
+            knockback: 1.0,
+            owner: Entity::PLACEHOLDER,
+            status_effect: None,
+        },
+        ProjectileRange {
+            origin: Vec2::ZERO,
+            max_distance: 600.0,
+        },
+        ProjectileLifetime(Timer::from_seconds(1.5, TimerMode::Once)),
+        ProjectileHits::default(),
+        Pierce(0),
+        Ricochet(0),
+        DamageFalloff::default(),
+        ImpactEffect {
+            frames: Vec::new(),
+            frame_seconds: 0.05,
+        },
+    )
+}
+
+fn shot_velocity(shot: usize) -> Vec2 {
+    Vec2::from_angle(shot as f32) * 800.0
+}
+
+/// How projectiles were created before pooling: a new entity per shot, despawned on expiry.
+fn fire_fresh_system(mut commands: Commands, mut in_flight: ResMut<InFlight>) {
+    if in_flight.0.len() == LIFETIME_FRAMES {
+        for entity in in_flight.0.pop_front().unwrap_or_default() {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    let fired = (0..SHOTS_PER_FRAME)
+        .map(|shot| {
+            commands
+                .spawn((
+                    gameplay_components(),
+                    Sprite::default(),
+                    Transform::default(),
+                    RigidBody::Dynamic,
+                    Collider::round_rectangle(4.0, 1.0, 0.5),
+                    Sensor,
+                    LockedAxes::ROTATION_LOCKED,
+                    LinearVelocity(shot_velocity(shot)),
+                    CollisionLayers::new(
+                        GameLayer::PROJECTILE,
+                        [GameLayer::ENEMY, GameLayer::TERRAIN],
+                    ),
+                    SweptCcd::default(),
+                ))
+                .id()
+        })
+        .collect();
+    in_flight.0.push_back(fired);
+}
+
+fn fire_pooled_system(
+    mut commands: Commands,
+    mut pool: ResMut<ProjectilePool>,
+    mut in_flight: ResMut<InFlight>,
+) {
+    if in_flight.0.len() == LIFETIME_FRAMES {
+        for entity in in_flight.0.pop_front().unwrap_or_default() {
+            release_projectile(&mut commands, &mut pool, entity);
+        }
+    }
+
+    let fired = (0..SHOTS_PER_FRAME)
+        .map(|shot| {
+            launch_projectile(
+                &mut commands,
+                &mut pool,
+                gameplay_components(),
+                Sprite::default(),
+                Transform::default(),
+                shot_velocity(shot),
+            )
+        })
+        .collect();
+    in_flight.0.push_back(fired);
+}
+
+fn benchmark_app(pooled: bool) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, TransformPlugin, PhysicsPlugins::default()))
+        .insert_resource(Gravity(Vec2::ZERO))
+        .init_resource::<InFlight>()
+        .init_resource::<ProjectilePool>()
+        .init_resource::<EffectPool>();
+    if pooled {
+        app.add_systems(Startup, prewarm_pools_system)
+            .add_systems(Update, fire_pooled_system);
+    } else {
+        app.add_systems(Update, fire_fresh_system);
+    }
+    // Let startup and prewarming run outside of the measurement.
+    app.update();
+    app
+}
+
+fn run(pooled: bool) -> Duration {
+    let mut app = benchmark_app(pooled);
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        app.update();
+    }
+    let elapsed = start.elapsed();
+
+    if pooled {
+        let metrics = app.world().resource::<ProjectilePool>().metrics();
+        println!(
+            "pool: {} spawned, {} reused, peak {} active, {:.1}% reuse",
+            metrics.spawned,
+            metrics.reused,
+            metrics.peak_active,
+            metrics.reuse_ratio() * 100.0
+        );
+    }
+    elapsed
+}
+
+pub fn main() {
+    println!(
+        "{} frames, {} shots per frame, projectiles live {} frames",
+        FRAMES, SHOTS_PER_FRAME, LIFETIME_FRAMES
+    );
+    let fresh = run(false);
+    let pooled = run(true);
+
+    let per_frame = |duration: Duration| duration.as_secs_f64() * 1000.0 / FRAMES as f64;
+    println!("spawn/despawn: {:.3} ms per frame", per_frame(fresh));
+    println!("pooled:        {:.3} ms per frame", per_frame(pooled));
+    println!(
+        "pooling takes {:.1}% of the spawn/despawn time",
+        pooled.as_secs_f64() / fresh.as_secs_f64() * 100.0
+    );
+}
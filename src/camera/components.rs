@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+/// State of the player camera. `position` is the camera position without shake applied.
+#[derive(Component, Debug)]
+pub struct CameraRig {
+    pub position: Vec2,
+    pub focus: Vec2,
+    pub look_ahead: Vec2,
+    pub target_zoom: f32,
+    pub trauma: f32,
+}
+
+impl CameraRig {
+    pub fn new(position: Vec2) -> Self {
+        Self {
+            position,
+            focus: position,
+            look_ahead: Vec2::ZERO,
+            target_zoom: 1.0,
+            trauma: 0.0,
+        }
+    }
+
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0.0, 1.0);
+    }
+}
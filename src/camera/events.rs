@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+/// Adds trauma to the camera, which makes it shake. Values are in `0.0..=1.0`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct CameraShakeEvent(pub f32);
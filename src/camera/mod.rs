@@ -0,0 +1,32 @@
+use bevy::{prelude::*, transform::TransformSystem};
+use events::CameraShakeEvent;
+use resources::{AccessibilitySettings, CameraSettings};
+use systems::*;
+
+pub mod components;
+pub mod events;
+pub mod resources;
+mod systems;
+mod util;
+
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CameraSettings>()
+            .init_resource::<AccessibilitySettings>()
+            .add_event::<CameraShakeEvent>()
+            .add_systems(
+                PostUpdate,
+                (
+                    camera_zoom_input_system,
+                    camera_zoom_system,
+                    camera_follow_system,
+                    camera_trauma_system,
+                    camera_shake_system,
+                )
+                    .chain()
+                    .before(TransformSystem::TransformPropagate),
+            );
+    }
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+
+#[derive(Resource, Debug, Clone)]
+pub struct CameraSettings {
+    /// Half size of the rectangle the player can move in without moving the camera.
+    pub dead_zone: Vec2,
+    /// How fast the camera catches up with its target. Higher is snappier.
+    pub follow_decay_rate: f32,
+    /// How far the camera leads in the aim direction.
+    pub look_ahead_distance: f32,
+    pub look_ahead_decay_rate: f32,
+    pub min_zoom: f32,
+    pub max_zoom: f32,
+    /// Zoom factor change per mouse wheel line.
+    pub wheel_zoom_step: f32,
+    /// Zoom change per second at full gamepad stick deflection.
+    pub gamepad_zoom_speed: f32,
+    pub zoom_decay_rate: f32,
+    /// The camera view is kept inside these bounds if set.
+    pub world_bounds: Option<Rect>,
+    pub max_shake_offset: f32,
+    pub max_shake_angle: f32,
+    pub shake_frequency: f32,
+    /// Trauma removed per second.
+    pub trauma_decay: f32,
+}
+
+impl Default for CameraSettings {
+    fn default() -> Self {
+        Self {
+            dead_zone: Vec2::new(24.0, 16.0),
+            follow_decay_rate: 4.0,
+            look_ahead_distance: 40.0,
+            look_ahead_decay_rate: 3.0,
+            min_zoom: 0.2,
+            max_zoom: 5.0,
+            wheel_zoom_step: 0.1,
+            gamepad_zoom_speed: 1.5,
+            zoom_decay_rate: 10.0,
+            world_bounds: None,
+            max_shake_offset: 12.0,
+            max_shake_angle: 0.05,
+            shake_frequency: 15.0,
+            trauma_decay: 1.5,
+        }
+    }
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct AccessibilitySettings {
+    /// Disables screen shake.
+    pub reduced_motion: bool,
+}
@@ -0,0 +1,146 @@
+use bevy::{
+    input::mouse::{AccumulatedMouseScroll, MouseScrollUnit},
+    prelude::*,
+};
+use noise::{NoiseFn, Perlin};
+
+use crate::player::{
+    components::{DamageInvulnerability, Player, PlayerAim, PlayerCamera},
+    weapon::events::ShotFiredEvent,
+};
+
+use super::{
+    components::CameraRig,
+    events::CameraShakeEvent,
+    resources::{AccessibilitySettings, CameraSettings},
+    util::{apply_dead_zone, clamp_to_bounds},
+};
+
+const FIRE_TRAUMA: f32 = 0.12;
+const DAMAGE_TRAUMA: f32 = 0.45;
+const GAMEPAD_STICK_DEADZONE: f32 = 0.2;
+
+pub fn camera_follow_system(
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    mut camera_query: Query<(&mut CameraRig, &OrthographicProjection, &Transform), With<PlayerCamera>>,
+    player_query: Query<(&Transform, &PlayerAim), (With<Player>, Without<PlayerCamera>)>,
+) {
+    let Ok((mut rig, projection, camera_transform)) = camera_query.get_single_mut() else {
+        return;
+    };
+    let Ok((player_transform, aim)) = player_query.get_single() else {
+        return;
+    };
+    let delta = time.delta_secs();
+
+    let player_position = player_transform.translation.truncate();
+    rig.focus = apply_dead_zone(rig.focus, player_position, settings.dead_zone);
+
+    let look_ahead_target = aim.0 * settings.look_ahead_distance;
+    rig.look_ahead
+        .smooth_nudge(&look_ahead_target, settings.look_ahead_decay_rate, delta);
+
+    let mut target = rig.focus + rig.look_ahead;
+    if let Some(bounds) = settings.world_bounds {
+        let half_view = projection.area.half_size() * camera_transform.scale.truncate();
+        target = clamp_to_bounds(target, half_view, bounds);
+    }
+    rig.position
+        .smooth_nudge(&target, settings.follow_decay_rate, delta);
+}
+
+pub fn camera_zoom_input_system(
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_scroll: Res<AccumulatedMouseScroll>,
+    gamepads: Query<&Gamepad>,
+    mut camera_query: Query<&mut CameraRig, With<PlayerCamera>>,
+) {
+    let Ok(mut rig) = camera_query.get_single_mut() else {
+        return;
+    };
+    let mut zoom = rig.target_zoom;
+
+    // Without a modifier the mouse wheel cycles through the hotbar.
+    if keyboard_input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+        let scrolled_lines = match mouse_scroll.unit {
+            MouseScrollUnit::Line => mouse_scroll.delta.y,
+            MouseScrollUnit::Pixel => mouse_scroll.delta.y / 16.0,
+        };
+        zoom *= 1.0 - scrolled_lines * settings.wheel_zoom_step;
+    }
+
+    for gamepad in gamepads.iter() {
+        let stick = gamepad.right_stick().y;
+        if stick.abs() > GAMEPAD_STICK_DEADZONE {
+            zoom -= stick * settings.gamepad_zoom_speed * zoom * time.delta_secs();
+        }
+    }
+
+    rig.target_zoom = zoom.clamp(settings.min_zoom, settings.max_zoom);
+}
+
+pub fn camera_zoom_system(
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    mut camera_query: Query<(&CameraRig, &mut OrthographicProjection), With<PlayerCamera>>,
+) {
+    for (rig, mut projection) in camera_query.iter_mut() {
+        projection
+            .scale
+            .smooth_nudge(&rig.target_zoom, settings.zoom_decay_rate, time.delta_secs());
+    }
+}
+
+pub fn camera_trauma_system(
+    mut shake_events: EventReader<CameraShakeEvent>,
+    mut shot_fired_events: EventReader<ShotFiredEvent>,
+    damaged_player_query: Query<(), (With<Player>, Added<DamageInvulnerability>)>,
+    mut camera_query: Query<&mut CameraRig, With<PlayerCamera>>,
+) {
+    let Ok(mut rig) = camera_query.get_single_mut() else {
+        return;
+    };
+
+    for event in shake_events.read() {
+        rig.add_trauma(event.0);
+    }
+    for _ in shot_fired_events.read() {
+        rig.add_trauma(FIRE_TRAUMA);
+    }
+    if !damaged_player_query.is_empty() {
+        rig.add_trauma(DAMAGE_TRAUMA);
+    }
+}
+
+/// Writes the rig position to the camera transform and adds noise based shake on top.
+pub fn camera_shake_system(
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    accessibility_settings: Res<AccessibilitySettings>,
+    mut noise: Local<Option<Perlin>>,
+    mut camera_query: Query<(&mut CameraRig, &mut Transform), With<PlayerCamera>>,
+) {
+    let noise = noise.get_or_insert_with(|| Perlin::new(rand::random()));
+
+    for (mut rig, mut transform) in camera_query.iter_mut() {
+        rig.trauma = (rig.trauma - settings.trauma_decay * time.delta_secs()).max(0.0);
+
+        let mut offset = Vec2::ZERO;
+        let mut angle = 0.0;
+        if !accessibility_settings.reduced_motion && rig.trauma > 0.0 {
+            let shake = rig.trauma * rig.trauma;
+            let t = (time.elapsed_secs() * settings.shake_frequency) as f64;
+            offset = Vec2::new(noise.get([t, 0.0]) as f32, noise.get([t, 10.0]) as f32)
+                * settings.max_shake_offset
+                * shake;
+            angle = noise.get([t, 20.0]) as f32 * settings.max_shake_angle * shake;
+        }
+
+        transform.translation.x = rig.position.x + offset.x;
+        transform.translation.y = rig.position.y + offset.y;
+        transform.rotation = Quat::from_rotation_z(angle);
+    }
+}
@@ -0,0 +1,60 @@
+use bevy::math::{Rect, Vec2};
+
+/// Moves `focus` just enough that `target` lies inside the dead zone around it.
+pub fn apply_dead_zone(focus: Vec2, target: Vec2, dead_zone: Vec2) -> Vec2 {
+    let delta = target - focus;
+    focus + (delta - delta.clamp(-dead_zone, dead_zone))
+}
+
+/// Keeps a view of `half_view` size centered on `position` inside `bounds`.
+/// Centers the view on an axis where the bounds are smaller than the view.
+pub fn clamp_to_bounds(position: Vec2, half_view: Vec2, bounds: Rect) -> Vec2 {
+    let min = bounds.min + half_view;
+    let max = bounds.max - half_view;
+    let center = bounds.center();
+    Vec2::new(
+        if min.x > max.x {
+            center.x
+        } else {
+            position.x.clamp(min.x, max.x)
+        },
+        if min.y > max.y {
+            center.y
+        } else {
+            position.y.clamp(min.y, max.y)
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dead_zone_keeps_focus_while_inside() {
+        let focus = apply_dead_zone(Vec2::ZERO, Vec2::new(5.0, -3.0), Vec2::new(10.0, 10.0));
+        assert_eq!(focus, Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_dead_zone_drags_focus_to_edge() {
+        let focus = apply_dead_zone(Vec2::ZERO, Vec2::new(25.0, -3.0), Vec2::new(10.0, 10.0));
+        assert_eq!(focus, Vec2::new(15.0, 0.0));
+    }
+
+    #[test]
+    fn test_clamp_to_bounds() {
+        let bounds = Rect::new(-100.0, -100.0, 100.0, 100.0);
+        let half_view = Vec2::new(20.0, 20.0);
+
+        assert_eq!(
+            clamp_to_bounds(Vec2::new(95.0, 0.0), half_view, bounds),
+            Vec2::new(80.0, 0.0)
+        );
+        assert_eq!(
+            clamp_to_bounds(Vec2::new(95.0, 0.0), Vec2::new(150.0, 20.0), bounds),
+            Vec2::new(0.0, 0.0),
+            "A view wider than the bounds stays centered"
+        );
+    }
+}
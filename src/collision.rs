@@ -7,4 +7,7 @@ pub enum GameLayer {
     PLAYER,
     ENEMY,
     PROJECTILE,
+    TERRAIN,
+    MELEE,
+    ENEMY_PROJECTILE,
 }
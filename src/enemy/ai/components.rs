@@ -0,0 +1,470 @@
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+
+/// Distance at which a walk target counts as reached.
+const ARRIVE_DISTANCE: f32 = 8.0;
+/// Seconds an enemy stands around before it wanders off again.
+const IDLE_SECONDS: (f32, f32) = (1.0, 3.0);
+/// Seconds spent looking for the source of a noise or the player's last known position.
+const INVESTIGATE_SECONDS: f32 = 6.0;
+const WANDER_SPEED: f32 = 0.5;
+/// Seconds after which a wander target that cannot be reached is given up.
+const WANDER_SECONDS: f32 = 4.0;
+/// How far off its preferred distance an enemy may be before it moves again.
+const PREFERRED_DISTANCE_SLACK: f32 = 30.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct AttackSettings {
+    /// Distance to the player at which the windup starts.
+    pub range: f32,
+    /// Seconds the enemy stands still before it strikes, giving the player time to react.
+    pub windup_seconds: f32,
+    /// Seconds of the lunge at the player. Without a lunge the enemy stands still while the
+    /// attack is released, which is what ranged enemies do.
+    #[serde(default)]
+    pub lunge_seconds: f32,
+    /// Multiplier on the movement speed during the lunge.
+    #[serde(default)]
+    pub lunge_speed: f32,
+    pub cooldown_seconds: f32,
+}
+
+/// Which behaviours an enemy mixes into its state machine and how well it perceives the player.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Deserialize)]
+#[require(EnemyAi, Awareness)]
+pub struct AiProfile {
+    pub sight_radius: f32,
+    /// Multiplier on how far away noises like gunshots are heard, zero makes the enemy deaf.
+    #[serde(default = "default_hearing")]
+    pub hearing: f32,
+    /// Seconds the enemy keeps chasing the player's last known position after losing sight.
+    pub memory_seconds: f32,
+    /// Distance idle enemies wander from where they stand, without one they wait in place.
+    #[serde(default)]
+    pub wander_radius: Option<f32>,
+    #[serde(default)]
+    pub attack: Option<AttackSettings>,
+    /// Seconds the enemy backs off after an attack before chasing again.
+    #[serde(default)]
+    pub retreat_seconds: Option<f32>,
+    /// Distance to the player a chasing enemy tries to keep, it backs off when the player
+    /// comes closer. Without one it walks right up to the player.
+    #[serde(default)]
+    pub preferred_distance: Option<f32>,
+}
+
+fn default_hearing() -> f32 {
+    1.0
+}
+
+impl Default for AiProfile {
+    fn default() -> Self {
+        Self {
+            sight_radius: 400.0,
+            hearing: default_hearing(),
+            memory_seconds: 2.0,
+            wander_radius: None,
+            attack: None,
+            retreat_seconds: None,
+            preferred_distance: None,
+        }
+    }
+}
+
+/// What an enemy currently knows about the player, written by the perception system.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct Awareness {
+    pub sees_player: bool,
+    /// Where the player was when the enemy last saw them.
+    pub last_seen: Option<Vec2>,
+    pub unseen_seconds: f32,
+    /// Closest noise heard this frame.
+    pub heard_noise: Option<Vec2>,
+}
+
+impl Awareness {
+    /// Awareness of an enemy sent after the player, like the ones waves bring in from outside
+    /// the view. It heads for where the player is and picks them up once in sight.
+    pub fn hunting(player_position: Vec2) -> Self {
+        Self {
+            last_seen: Some(player_position),
+            ..default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AiState {
+    Idle { seconds_left: f32 },
+    Wander { target: Vec2, seconds_left: f32 },
+    Investigate { target: Vec2, seconds_left: f32 },
+    Chase,
+    AttackWindup { seconds_left: f32 },
+    Attack { seconds_left: f32, direction: Vec2 },
+    Retreat { seconds_left: f32 },
+}
+
+impl Default for AiState {
+    fn default() -> Self {
+        AiState::Idle { seconds_left: 0.0 }
+    }
+}
+
+/// Per-enemy AI state machine. Transitions are driven by the enemy's `Awareness`, which
+/// behaviours are available comes from its `AiProfile`.
+#[derive(Component, Debug, Default)]
+pub struct EnemyAi {
+    pub state: AiState,
+    /// Seconds until the enemy can attack again.
+    pub attack_cooldown: f32,
+}
+
+impl EnemyAi {
+    pub fn hunting() -> Self {
+        Self {
+            state: AiState::Chase,
+            ..default()
+        }
+    }
+
+    /// Advances the state machine by `delta` seconds. Returns true when an attack is released
+    /// this frame.
+    pub fn update(
+        &mut self,
+        profile: &AiProfile,
+        awareness: &Awareness,
+        position: Vec2,
+        delta: f32,
+        rng: &mut impl Rng,
+    ) -> bool {
+        self.attack_cooldown = (self.attack_cooldown - delta).max(0.0);
+
+        match self.state {
+            AiState::Idle { .. } | AiState::Wander { .. } | AiState::Investigate { .. }
+                if awareness.sees_player =>
+            {
+                self.state = AiState::Chase;
+            }
+            AiState::Idle { .. } | AiState::Wander { .. } | AiState::Investigate { .. }
+                if awareness.heard_noise.is_some() =>
+            {
+                self.state = AiState::Investigate {
+                    target: awareness.heard_noise.unwrap_or(position),
+                    seconds_left: INVESTIGATE_SECONDS,
+                };
+            }
+            AiState::Idle { seconds_left } => {
+                if seconds_left > delta {
+                    self.state = AiState::Idle {
+                        seconds_left: seconds_left - delta,
+                    };
+                } else if let Some(radius) = profile.wander_radius {
+                    let offset = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU))
+                        * rng.gen_range(0.0..=radius);
+                    self.state = AiState::Wander {
+                        target: position + offset,
+                        seconds_left: WANDER_SECONDS,
+                    };
+                } else {
+                    self.state = idle(rng);
+                }
+            }
+            AiState::Wander {
+                target,
+                seconds_left,
+            } => {
+                if position.distance(target) <= ARRIVE_DISTANCE || seconds_left <= delta {
+                    self.state = idle(rng);
+                } else {
+                    self.state = AiState::Wander {
+                        target,
+                        seconds_left: seconds_left - delta,
+                    };
+                }
+            }
+            AiState::Investigate {
+                target,
+                seconds_left,
+            } => {
+                if position.distance(target) <= ARRIVE_DISTANCE || seconds_left <= delta {
+                    self.state = idle(rng);
+                } else {
+                    self.state = AiState::Investigate {
+                        target,
+                        seconds_left: seconds_left - delta,
+                    };
+                }
+            }
+            AiState::Chase => {
+                let in_range = match (profile.attack, awareness.last_seen) {
+                    (Some(attack), Some(last_seen)) => position.distance(last_seen) <= attack.range,
+                    _ => false,
+                };
+                if awareness.sees_player && in_range && self.attack_cooldown <= 0.0 {
+                    self.state = AiState::AttackWindup {
+                        seconds_left: profile.attack.map_or(0.0, |attack| attack.windup_seconds),
+                    };
+                } else if !awareness.sees_player
+                    && awareness.unseen_seconds > profile.memory_seconds
+                {
+                    self.state = match awareness.last_seen {
+                        Some(last_seen) => AiState::Investigate {
+                            target: last_seen,
+                            seconds_left: INVESTIGATE_SECONDS,
+                        },
+                        None => idle(rng),
+                    };
+                }
+            }
+            AiState::AttackWindup { seconds_left } => {
+                if seconds_left > delta {
+                    self.state = AiState::AttackWindup {
+                        seconds_left: seconds_left - delta,
+                    };
+                } else {
+                    let direction = awareness.last_seen.map_or(Vec2::ZERO, |last_seen| {
+                        (last_seen - position).normalize_or_zero()
+                    });
+                    self.state = AiState::Attack {
+                        seconds_left: profile.attack.map_or(0.0, |attack| attack.lunge_seconds),
+                        direction,
+                    };
+                    return true;
+                }
+            }
+            AiState::Attack {
+                seconds_left,
+                direction,
+            } => {
+                if seconds_left > delta {
+                    self.state = AiState::Attack {
+                        seconds_left: seconds_left - delta,
+                        direction,
+                    };
+                } else {
+                    self.attack_cooldown =
+                        profile.attack.map_or(0.0, |attack| attack.cooldown_seconds);
+                    self.state = match profile.retreat_seconds {
+                        Some(seconds_left) => AiState::Retreat { seconds_left },
+                        None => AiState::Chase,
+                    };
+                }
+            }
+            AiState::Retreat { seconds_left } => {
+                if seconds_left > delta {
+                    self.state = AiState::Retreat {
+                        seconds_left: seconds_left - delta,
+                    };
+                } else {
+                    self.state = AiState::Chase;
+                }
+            }
+        }
+        false
+    }
+
+    /// Direction of the attack being released, `None` outside of an attack.
+    pub fn attack_direction(&self) -> Option<Vec2> {
+        match self.state {
+            AiState::Attack { direction, .. } => Some(direction),
+            _ => None,
+        }
+    }
+
+    /// Direction the enemy wants to move in, scaled by the fraction of its speed to use.
+    pub fn steering(&self, profile: &AiProfile, awareness: &Awareness, position: Vec2) -> Vec2 {
+        let towards = |target: Vec2| (target - position).normalize_or_zero();
+        match self.state {
+            AiState::Idle { .. } | AiState::AttackWindup { .. } => Vec2::ZERO,
+            AiState::Wander { target, .. } => towards(target) * WANDER_SPEED,
+            AiState::Investigate { target, .. } => towards(target),
+            AiState::Chase => match (awareness.last_seen, profile.preferred_distance) {
+                (Some(last_seen), Some(preferred_distance)) => {
+                    let distance = position.distance(last_seen);
+                    if distance < preferred_distance - PREFERRED_DISTANCE_SLACK {
+                        -towards(last_seen)
+                    } else if distance > preferred_distance + PREFERRED_DISTANCE_SLACK {
+                        towards(last_seen)
+                    } else {
+                        Vec2::ZERO
+                    }
+                }
+                (Some(last_seen), None) => towards(last_seen),
+                (None, _) => Vec2::ZERO,
+            },
+            AiState::Attack { direction, .. } => {
+                direction * profile.attack.map_or(1.0, |attack| attack.lunge_speed)
+            }
+            AiState::Retreat { .. } => awareness
+                .last_seen
+                .map_or(Vec2::ZERO, |last_seen| -towards(last_seen)),
+        }
+    }
+}
+
+fn idle(rng: &mut impl Rng) -> AiState {
+    AiState::Idle {
+        seconds_left: rng.gen_range(IDLE_SECONDS.0..IDLE_SECONDS.1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn attacker() -> AiProfile {
+        AiProfile {
+            attack: Some(AttackSettings {
+                range: 30.0,
+                windup_seconds: 0.5,
+                lunge_seconds: 0.2,
+                lunge_speed: 3.0,
+                cooldown_seconds: 1.0,
+            }),
+            retreat_seconds: Some(1.0),
+            ..default()
+        }
+    }
+
+    fn seeing(player: Vec2) -> Awareness {
+        Awareness {
+            sees_player: true,
+            last_seen: Some(player),
+            ..default()
+        }
+    }
+
+    #[test]
+    fn test_attack_cycle_runs_windup_lunge_and_retreat() {
+        let profile = attacker();
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut ai = EnemyAi {
+            state: AiState::Chase,
+            ..default()
+        };
+        let awareness = seeing(Vec2::new(20.0, 0.0));
+
+        assert!(!ai.update(&profile, &awareness, Vec2::ZERO, 0.1, &mut rng));
+        assert!(matches!(ai.state, AiState::AttackWindup { .. }));
+        assert_eq!(ai.steering(&profile, &awareness, Vec2::ZERO), Vec2::ZERO);
+
+        assert!(ai.update(&profile, &awareness, Vec2::ZERO, 0.5, &mut rng));
+        assert_eq!(
+            ai.steering(&profile, &awareness, Vec2::ZERO),
+            Vec2::new(3.0, 0.0)
+        );
+
+        ai.update(&profile, &awareness, Vec2::ZERO, 0.2, &mut rng);
+        assert!(matches!(ai.state, AiState::Retreat { .. }));
+        assert!(ai.steering(&profile, &awareness, Vec2::ZERO).x < 0.0);
+        assert_eq!(ai.attack_cooldown, 1.0);
+    }
+
+    #[test]
+    fn test_lost_player_is_investigated_then_forgotten() {
+        let profile = AiProfile::default();
+        let mut rng = StdRng::seed_from_u64(2);
+        let mut ai = EnemyAi {
+            state: AiState::Chase,
+            ..default()
+        };
+        let lost = Awareness {
+            sees_player: false,
+            last_seen: Some(Vec2::new(100.0, 0.0)),
+            unseen_seconds: profile.memory_seconds + 0.1,
+            heard_noise: None,
+        };
+
+        ai.update(&profile, &lost, Vec2::ZERO, 0.1, &mut rng);
+        assert_eq!(
+            ai.state,
+            AiState::Investigate {
+                target: Vec2::new(100.0, 0.0),
+                seconds_left: INVESTIGATE_SECONDS
+            }
+        );
+        ai.update(&profile, &lost, Vec2::new(99.0, 0.0), 0.1, &mut rng);
+        assert!(matches!(ai.state, AiState::Idle { .. }));
+    }
+
+    #[test]
+    fn test_chase_keeps_preferred_distance() {
+        let profile = AiProfile {
+            preferred_distance: Some(200.0),
+            ..default()
+        };
+        let ai = EnemyAi {
+            state: AiState::Chase,
+            ..default()
+        };
+        let steering_at = |x: f32| ai.steering(&profile, &seeing(Vec2::ZERO), Vec2::new(x, 0.0));
+
+        assert_eq!(steering_at(100.0), Vec2::X);
+        assert_eq!(steering_at(210.0), Vec2::ZERO);
+        assert_eq!(steering_at(300.0), -Vec2::X);
+    }
+
+    #[test]
+    fn test_attack_without_lunge_stands_still() {
+        let profile = AiProfile {
+            attack: Some(AttackSettings {
+                range: 300.0,
+                windup_seconds: 0.5,
+                lunge_seconds: 0.0,
+                lunge_speed: 0.0,
+                cooldown_seconds: 2.0,
+            }),
+            ..default()
+        };
+        let mut rng = StdRng::seed_from_u64(4);
+        let mut ai = EnemyAi {
+            state: AiState::AttackWindup { seconds_left: 0.1 },
+            ..default()
+        };
+        let awareness = seeing(Vec2::new(0.0, 250.0));
+
+        assert!(ai.update(&profile, &awareness, Vec2::ZERO, 0.1, &mut rng));
+        assert_eq!(ai.attack_direction(), Some(Vec2::Y));
+        assert_eq!(ai.steering(&profile, &awareness, Vec2::ZERO), Vec2::ZERO);
+        ai.update(&profile, &awareness, Vec2::ZERO, 0.1, &mut rng);
+        assert_eq!(ai.state, AiState::Chase);
+        assert_eq!(ai.attack_cooldown, 2.0);
+    }
+
+    #[test]
+    fn test_hunting_enemy_heads_for_the_player_out_of_sight() {
+        let profile = AiProfile::default();
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut ai = EnemyAi::hunting();
+        let mut awareness = Awareness::hunting(Vec2::new(600.0, 0.0));
+
+        ai.update(&profile, &awareness, Vec2::ZERO, 0.1, &mut rng);
+        assert_eq!(ai.state, AiState::Chase);
+        assert_eq!(ai.steering(&profile, &awareness, Vec2::ZERO), Vec2::X);
+
+        awareness.unseen_seconds = profile.memory_seconds + 0.1;
+        ai.update(&profile, &awareness, Vec2::new(200.0, 0.0), 0.1, &mut rng);
+        assert!(matches!(
+            ai.state,
+            AiState::Investigate { target, .. } if target == Vec2::new(600.0, 0.0)
+        ));
+    }
+
+    #[test]
+    fn test_noise_is_investigated_and_sight_wins() {
+        let profile = AiProfile::default();
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut ai = EnemyAi::default();
+        let heard = Awareness {
+            heard_noise: Some(Vec2::new(0.0, 50.0)),
+            ..default()
+        };
+
+        ai.update(&profile, &heard, Vec2::ZERO, 0.1, &mut rng);
+        assert!(matches!(ai.state, AiState::Investigate { .. }));
+        ai.update(&profile, &seeing(Vec2::X), Vec2::ZERO, 0.1, &mut rng);
+        assert_eq!(ai.state, AiState::Chase);
+    }
+}
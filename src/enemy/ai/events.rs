@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+
+/// A sound enemies can hear, like a gunshot or an explosion. Enemies within `radius`
+/// (scaled by their hearing) go to investigate it.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct NoiseEvent {
+    pub position: Vec2,
+    pub radius: f32,
+}
+
+/// Sent when an enemy's attack windup ends and the attack is released.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct EnemyAttackEvent {
+    pub enemy: Entity,
+    pub direction: Vec2,
+}
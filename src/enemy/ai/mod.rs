@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+use events::{EnemyAttackEvent, NoiseEvent};
+use systems::*;
+
+use crate::AppState;
+
+use super::systems::enemy_movement_and_direction_system;
+
+pub mod components;
+pub mod events;
+mod systems;
+
+/// Enemy perception and the per-enemy state machine that decides how enemies move.
+pub struct AiPlugin;
+
+impl Plugin for AiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<NoiseEvent>()
+            .add_event::<EnemyAttackEvent>()
+            .add_systems(
+                Update,
+                (noise_system, perception_system, ai_state_system)
+                    .chain()
+                    .before(enemy_movement_and_direction_system)
+                    .run_if(in_state(AppState::InGame)),
+            );
+    }
+}
@@ -0,0 +1,196 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use rand::thread_rng;
+
+use crate::{
+    collision::GameLayer,
+    explosives::events::ExplosionEvent,
+    player::{components::Player, weapon::events::ShotFiredEvent},
+};
+
+use super::{
+    components::{AiProfile, Awareness, EnemyAi},
+    events::{EnemyAttackEvent, NoiseEvent},
+};
+
+const GUNSHOT_NOISE_RADIUS: f32 = 650.0;
+const EXPLOSION_NOISE_RADIUS: f32 = 900.0;
+
+/// Turns gunshots and explosions into noises enemies can hear.
+pub fn noise_system(
+    player_query: Query<&Transform, With<Player>>,
+    mut shot_fired_events: EventReader<ShotFiredEvent>,
+    mut explosion_events: EventReader<ExplosionEvent>,
+    mut noise_events: EventWriter<NoiseEvent>,
+) {
+    if let Ok(player_transform) = player_query.get_single() {
+        if shot_fired_events.read().count() > 0 {
+            noise_events.send(NoiseEvent {
+                position: player_transform.translation.truncate(),
+                radius: GUNSHOT_NOISE_RADIUS,
+            });
+        }
+    }
+    for event in explosion_events.read() {
+        noise_events.send(NoiseEvent {
+            position: event.position,
+            radius: EXPLOSION_NOISE_RADIUS,
+        });
+    }
+}
+
+/// Updates what every enemy knows about the player. Enemies see the player within their
+/// sight radius unless terrain blocks the line of sight, and hear noises within range.
+pub fn perception_system(
+    time: Res<Time>,
+    spatial_query: SpatialQuery,
+    player_query: Query<&Transform, With<Player>>,
+    mut noise_events: EventReader<NoiseEvent>,
+    mut enemy_query: Query<(&Transform, &AiProfile, &mut Awareness)>,
+) {
+    let noises: Vec<NoiseEvent> = noise_events.read().copied().collect();
+    let player_position = player_query
+        .get_single()
+        .ok()
+        .map(|transform| transform.translation.truncate());
+    let terrain = SpatialQueryFilter::from_mask(GameLayer::TERRAIN);
+
+    for (transform, profile, mut awareness) in enemy_query.iter_mut() {
+        let position = transform.translation.truncate();
+
+        let sees_player = player_position.is_some_and(|player_position| {
+            let to_player = player_position - position;
+            let distance = to_player.length();
+            if distance > profile.sight_radius {
+                return false;
+            }
+            let Ok(direction) = Dir2::new(to_player) else {
+                return true;
+            };
+            spatial_query
+                .cast_ray(position, direction, distance, true, &terrain)
+                .is_none()
+        });
+
+        awareness.sees_player = sees_player;
+        if let (true, Some(player_position)) = (sees_player, player_position) {
+            awareness.last_seen = Some(player_position);
+            awareness.unseen_seconds = 0.0;
+        } else {
+            awareness.unseen_seconds += time.delta_secs();
+        }
+
+        awareness.heard_noise = noises
+            .iter()
+            .filter(|noise| position.distance(noise.position) <= noise.radius * profile.hearing)
+            .min_by(|a, b| {
+                position
+                    .distance_squared(a.position)
+                    .total_cmp(&position.distance_squared(b.position))
+            })
+            .map(|noise| noise.position);
+    }
+}
+
+pub fn ai_state_system(
+    time: Res<Time>,
+    mut enemy_query: Query<(Entity, &Transform, &AiProfile, &Awareness, &mut EnemyAi)>,
+    mut attack_events: EventWriter<EnemyAttackEvent>,
+) {
+    let mut rng = thread_rng();
+    for (entity, transform, profile, awareness, mut ai) in enemy_query.iter_mut() {
+        let position = transform.translation.truncate();
+        if ai.update(profile, awareness, position, time.delta_secs(), &mut rng) {
+            attack_events.send(EnemyAttackEvent {
+                enemy: entity,
+                direction: ai.attack_direction().unwrap_or_default(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enemy::ai::components::{AiState, AttackSettings};
+    use std::time::Duration;
+
+    fn headless_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_event::<EnemyAttackEvent>()
+            .add_systems(Update, ai_state_system);
+        app
+    }
+
+    fn advance(app: &mut App, seconds: f32) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(seconds));
+        app.update();
+    }
+
+    #[test]
+    fn test_enemy_in_range_winds_up_and_attacks() {
+        let mut app = headless_app();
+        let enemy = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                AiProfile {
+                    attack: Some(AttackSettings {
+                        range: 40.0,
+                        windup_seconds: 0.3,
+                        lunge_seconds: 0.2,
+                        lunge_speed: 2.0,
+                        cooldown_seconds: 1.0,
+                    }),
+                    ..default()
+                },
+                Awareness {
+                    sees_player: true,
+                    last_seen: Some(Vec2::new(0.0, 30.0)),
+                    ..default()
+                },
+            ))
+            .id();
+
+        advance(&mut app, 0.1);
+        assert_eq!(
+            app.world().get::<EnemyAi>(enemy).unwrap().state,
+            AiState::Chase
+        );
+        advance(&mut app, 0.1);
+        assert!(matches!(
+            app.world().get::<EnemyAi>(enemy).unwrap().state,
+            AiState::AttackWindup { .. }
+        ));
+        advance(&mut app, 0.3);
+
+        let attacks: Vec<EnemyAttackEvent> = app
+            .world_mut()
+            .resource_mut::<Events<EnemyAttackEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(attacks.len(), 1);
+        assert_eq!(attacks[0].enemy, enemy);
+        assert_eq!(attacks[0].direction, Vec2::Y);
+    }
+
+    #[test]
+    fn test_enemy_without_perception_keeps_idling() {
+        let mut app = headless_app();
+        let enemy = app
+            .world_mut()
+            .spawn((Transform::default(), AiProfile::default()))
+            .id();
+
+        for _ in 0..10 {
+            advance(&mut app, 0.5);
+        }
+        assert!(matches!(
+            app.world().get::<EnemyAi>(enemy).unwrap().state,
+            AiState::Idle { .. }
+        ));
+    }
+}
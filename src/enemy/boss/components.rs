@@ -0,0 +1,165 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use super::resources::{BossAttack, BossPhase};
+
+/// A boss encounter. Its definition in the `BossRegistry` is looked up by `id`.
+#[derive(Component, Debug)]
+pub struct Boss {
+    pub id: String,
+    pub phase: usize,
+    pub max_health: f32,
+    /// Walking speed of the archetype, before the phase's multiplier.
+    pub base_speed: f32,
+}
+
+/// The boss' entrance, it stands still until the timer finishes.
+#[derive(Component, Debug)]
+pub struct BossIntro(pub Timer);
+
+/// Special attacks of a boss, on top of its regular AI which keeps walking after the player.
+#[derive(Component, Debug, Clone)]
+pub enum BossAttackState {
+    /// Walking after the player until the next special attack.
+    Idle { seconds_left: f32 },
+    Windup {
+        attack: BossAttack,
+        seconds_left: f32,
+    },
+    Charging {
+        direction: Vec2,
+        speed: f32,
+        seconds_left: f32,
+    },
+}
+
+impl BossAttackState {
+    pub fn idle(phase: &BossPhase, rng: &mut impl Rng) -> Self {
+        let (shortest, longest) = phase.attack_interval;
+        BossAttackState::Idle {
+            seconds_left: rng.gen_range(shortest..=longest.max(shortest)),
+        }
+    }
+
+    /// Advances the attack by `delta` seconds. Returns the attack released this frame, a charge
+    /// is not returned since it plays out in this state.
+    pub fn update(
+        &mut self,
+        phase: &BossPhase,
+        to_player: Vec2,
+        delta: f32,
+        rng: &mut impl Rng,
+    ) -> Option<BossAttack> {
+        match self {
+            BossAttackState::Idle { seconds_left } => {
+                if *seconds_left > delta {
+                    *seconds_left -= delta;
+                } else if phase.attacks.is_empty() {
+                    *self = Self::idle(phase, rng);
+                } else {
+                    let attack = phase.attacks[rng.gen_range(0..phase.attacks.len())].clone();
+                    *self = BossAttackState::Windup {
+                        seconds_left: attack.windup_seconds(),
+                        attack,
+                    };
+                }
+                None
+            }
+            BossAttackState::Windup {
+                attack,
+                seconds_left,
+            } => {
+                if *seconds_left > delta {
+                    *seconds_left -= delta;
+                    return None;
+                }
+                let attack = attack.clone();
+                if let BossAttack::Charge(charge) = attack {
+                    *self = BossAttackState::Charging {
+                        direction: to_player.normalize_or_zero(),
+                        speed: charge.speed,
+                        seconds_left: charge.seconds,
+                    };
+                    None
+                } else {
+                    *self = Self::idle(phase, rng);
+                    Some(attack)
+                }
+            }
+            BossAttackState::Charging { seconds_left, .. } => {
+                if *seconds_left > delta {
+                    *seconds_left -= delta;
+                } else {
+                    *self = Self::idle(phase, rng);
+                }
+                None
+            }
+        }
+    }
+
+    /// Velocity the attack forces on the boss, `None` leaves the movement to its AI.
+    pub fn velocity(&self) -> Option<Vec2> {
+        match self {
+            BossAttackState::Idle { .. } => None,
+            BossAttackState::Windup { .. } => Some(Vec2::ZERO),
+            BossAttackState::Charging {
+                direction, speed, ..
+            } => Some(*direction * *speed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enemy::boss::resources::{ChargeAttack, SlamAttack};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn phase(attack: BossAttack) -> BossPhase {
+        BossPhase {
+            health_below: 1.0,
+            speed_multiplier: 1.0,
+            attack_interval: (2.0, 2.0),
+            attacks: vec![attack],
+        }
+    }
+
+    #[test]
+    fn test_charge_dashes_at_the_player() {
+        let phase = phase(BossAttack::Charge(ChargeAttack {
+            windup_seconds: 0.5,
+            speed: 300.0,
+            seconds: 0.4,
+        }));
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut state = BossAttackState::Idle { seconds_left: 0.1 };
+        let to_player = Vec2::new(0.0, -50.0);
+
+        assert!(state.update(&phase, to_player, 0.1, &mut rng).is_none());
+        assert_eq!(state.velocity(), Some(Vec2::ZERO));
+        assert!(state.update(&phase, to_player, 0.5, &mut rng).is_none());
+        assert_eq!(state.velocity(), Some(Vec2::new(0.0, -300.0)));
+        state.update(&phase, to_player, 0.4, &mut rng);
+        assert!(matches!(state, BossAttackState::Idle { seconds_left } if seconds_left == 2.0));
+        assert_eq!(state.velocity(), None);
+    }
+
+    #[test]
+    fn test_slam_is_released_after_the_windup() {
+        let phase = phase(BossAttack::Slam(SlamAttack {
+            windup_seconds: 1.0,
+            radius: 100.0,
+            damage: 1,
+            knockback: 1.0,
+            camera_shake: 0.5,
+        }));
+        let mut rng = StdRng::seed_from_u64(2);
+        let mut state = BossAttackState::Idle { seconds_left: 0.0 };
+
+        assert!(state.update(&phase, Vec2::X, 0.1, &mut rng).is_none());
+        assert!(state.update(&phase, Vec2::X, 0.5, &mut rng).is_none());
+        let released = state.update(&phase, Vec2::X, 0.5, &mut rng);
+        assert!(matches!(released, Some(BossAttack::Slam(_))));
+        assert!(matches!(state, BossAttackState::Idle { .. }));
+    }
+}
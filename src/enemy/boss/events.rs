@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+use super::resources::BossAttack;
+
+#[derive(Event, Debug, Clone)]
+pub struct BossDefeatedEvent {
+    pub name: String,
+}
+
+/// Sent when a boss releases a summon or a slam.
+#[derive(Event, Debug, Clone)]
+pub struct BossAttackEvent {
+    pub attack: BossAttack,
+    pub position: Vec2,
+}
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use events::{BossAttackEvent, BossDefeatedEvent};
+use resources::{BossRegistry, PendingBoss};
+use systems::*;
+
+use crate::AppState;
+
+use super::systems::{despawn_killed_enemies_system, enemy_separation_system, kill_enemy_system};
+
+pub mod components;
+pub mod events;
+pub mod resources;
+mod systems;
+
+/// Boss encounters scheduled by the waves, with phases, special attacks and an entrance.
+pub struct BossPlugin;
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<BossAttackEvent>()
+            .add_event::<BossDefeatedEvent>()
+            .insert_resource(BossRegistry::load())
+            .init_resource::<PendingBoss>()
+            .add_systems(
+                Update,
+                (
+                    (queue_boss_system, spawn_boss_system).chain(),
+                    boss_phase_system,
+                    (boss_intro_system, boss_attack_system).after(enemy_separation_system),
+                    (boss_summon_system, boss_slam_system).after(boss_attack_system),
+                    boss_defeat_system
+                        .after(kill_enemy_system)
+                        .before(despawn_killed_enemies_system),
+                )
+                    .run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(OnExit(AppState::InGame), clear_pending_boss_system);
+    }
+}
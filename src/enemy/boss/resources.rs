@@ -0,0 +1,186 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct ChargeAttack {
+    pub windup_seconds: f32,
+    pub speed: f32,
+    pub seconds: f32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SummonAttack {
+    pub windup_seconds: f32,
+    /// Archetype id of the summoned minions.
+    pub archetype: String,
+    pub count: u32,
+    /// Distance from the boss the minions appear at.
+    pub radius: f32,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct SlamAttack {
+    pub windup_seconds: f32,
+    pub radius: f32,
+    /// Hearts the player loses when caught in the slam.
+    pub damage: u8,
+    /// Multiplier on how far the player is pushed back.
+    pub knockback: f32,
+    pub camera_shake: f32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum BossAttack {
+    /// Dashes at where the player stood when the windup ended.
+    Charge(ChargeAttack),
+    Summon(SummonAttack),
+    /// Hits everything around the boss.
+    Slam(SlamAttack),
+}
+
+impl BossAttack {
+    /// Seconds the boss stands still before the attack, giving the player time to react.
+    pub fn windup_seconds(&self) -> f32 {
+        match self {
+            BossAttack::Charge(charge) => charge.windup_seconds,
+            BossAttack::Summon(summon) => summon.windup_seconds,
+            BossAttack::Slam(slam) => slam.windup_seconds,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BossPhase {
+    /// Fraction of the boss' health below which the phase starts.
+    pub health_below: f32,
+    /// Multiplier on the archetype's walking speed.
+    pub speed_multiplier: f32,
+    /// Shortest and longest seconds between two special attacks.
+    pub attack_interval: (f32, f32),
+    pub attacks: Vec<BossAttack>,
+}
+
+/// What happens to the run once the boss is defeated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum BossDefeat {
+    /// The waves go on.
+    Continue,
+    WinRun,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BossDefinition {
+    /// Archetype id in `assets/data/enemies.ron` the boss is spawned as.
+    pub id: String,
+    pub name: String,
+    /// Seconds the boss waits after spawning while its health bar fills up.
+    pub intro_seconds: f32,
+    pub on_defeat: BossDefeat,
+    /// Phases ordered from full health down.
+    pub phases: Vec<BossPhase>,
+}
+
+impl BossDefinition {
+    /// Index of the phase for the given fraction of the boss' health.
+    pub fn phase_at(&self, health_fraction: f32) -> usize {
+        self.phases
+            .iter()
+            .rposition(|phase| health_fraction <= phase.health_below)
+            .unwrap_or(0)
+    }
+}
+
+/// Every boss, loaded from `assets/data/bosses.ron`.
+#[derive(Resource, Debug, Deserialize)]
+pub struct BossRegistry {
+    pub bosses: Vec<BossDefinition>,
+}
+
+impl BossRegistry {
+    pub fn load() -> Self {
+        ron::from_str(include_str!("../../../assets/data/bosses.ron"))
+            .expect("assets/data/bosses.ron should contain valid boss definitions")
+    }
+
+    pub fn get(&self, id: &str) -> Option<&BossDefinition> {
+        self.bosses.iter().find(|boss| boss.id == id)
+    }
+}
+
+/// Boss of a started wave that has not appeared yet. It waits for room under the population
+/// cap and a free spawn point.
+#[derive(Resource, Debug, Default)]
+pub struct PendingBoss(pub Option<BossSpawn>);
+
+#[derive(Debug, Clone)]
+pub struct BossSpawn {
+    pub id: String,
+    pub wave: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enemy::{resources::EnemyRegistry, waves::resources::WaveSettings};
+
+    #[test]
+    fn test_bosses_use_known_archetypes() {
+        let bosses = BossRegistry::load();
+        let enemies = EnemyRegistry::load();
+        for boss in bosses.bosses.iter() {
+            assert!(
+                enemies.get(&boss.id).is_some(),
+                "unknown archetype {}",
+                boss.id
+            );
+            assert!(!boss.phases.is_empty(), "{} has no phases", boss.id);
+            for phase in boss.phases.iter() {
+                assert!(
+                    !phase.attacks.is_empty(),
+                    "{} has a phase without attacks",
+                    boss.id
+                );
+                for attack in phase.attacks.iter() {
+                    if let BossAttack::Summon(summon) = attack {
+                        assert!(enemies.get(&summon.archetype).is_some());
+                    }
+                }
+            }
+        }
+
+        let waves = WaveSettings::load();
+        let scheduled = waves
+            .waves
+            .iter()
+            .filter_map(|wave| wave.boss.as_ref())
+            .chain(waves.procedural.boss.as_ref());
+        for id in scheduled {
+            assert!(bosses.get(id).is_some(), "unknown boss {}", id);
+        }
+    }
+
+    #[test]
+    fn test_phases_follow_health() {
+        let boss = BossDefinition {
+            id: "boss".into(),
+            name: "Boss".into(),
+            intro_seconds: 1.0,
+            on_defeat: BossDefeat::Continue,
+            phases: [1.0, 0.6, 0.3]
+                .into_iter()
+                .map(|health_below| BossPhase {
+                    health_below,
+                    speed_multiplier: 1.0,
+                    attack_interval: (1.0, 2.0),
+                    attacks: Vec::new(),
+                })
+                .collect(),
+        };
+        assert_eq!(boss.phase_at(1.0), 0);
+        assert_eq!(boss.phase_at(0.61), 0);
+        assert_eq!(boss.phase_at(0.6), 1);
+        assert_eq!(boss.phase_at(0.31), 1);
+        assert_eq!(boss.phase_at(0.1), 2);
+        assert_eq!(boss.phase_at(-0.5), 2);
+    }
+}
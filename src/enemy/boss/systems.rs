@@ -0,0 +1,438 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use rand::prelude::*;
+use std::f32::consts::TAU;
+
+use crate::{
+    camera::events::CameraShakeEvent,
+    enemy::{
+        ai::events::NoiseEvent,
+        components::{EnemyHealth, EnemyMovementSpeed},
+        events::EnemyKilledEvent,
+        population::{
+            is_walkable,
+            resources::{Population, SpawnPoints, SpawnTelegraphAssets},
+            spawn_telegraph,
+        },
+        resources::EnemyRegistry,
+        systems::spawn_enemy,
+        waves::{
+            components::WaveMember,
+            events::WaveStartedEvent,
+            resources::{WaveDirector, WavePhase, WaveSettings},
+        },
+    },
+    explosives::resources::ExplosiveAssets,
+    hit_feedback::{components::HitFlash, events::HitEvent},
+    map_genreation::resources::{GroundTiles, ObstacleTiles},
+    player::{
+        character::components::PassiveAbility,
+        components::{DamageInvulnerability, Player, PlayerHealth},
+        weapon::pool::{play_effect, EffectPool},
+    },
+    AppState,
+};
+
+use super::{
+    components::{Boss, BossAttackState, BossIntro},
+    events::{BossAttackEvent, BossDefeatedEvent},
+    resources::{BossAttack, BossDefeat, BossRegistry, BossSpawn, PendingBoss},
+};
+
+const BOSS_Z: f32 = 10.0;
+const INTRO_SHAKE: f32 = 0.6;
+const PHASE_SHAKE: f32 = 0.4;
+const SLAM_NOISE_RADIUS: f32 = 700.0;
+const SLAM_EFFECT_SCALE: f32 = 6.0;
+const EFFECT_Z: f32 = 10.0;
+const TELEGRAPH_COLOR: Color = Color::srgb(1.0, 0.35, 0.35);
+/// Pulses per second of a winding up boss' tint.
+const TELEGRAPH_PULSE: f32 = 3.0;
+
+/// Lines up the boss of a wave when the wave starts.
+pub fn queue_boss_system(
+    director: Res<WaveDirector>,
+    mut pending_boss: ResMut<PendingBoss>,
+    mut started_events: EventReader<WaveStartedEvent>,
+) {
+    for event in started_events.read() {
+        let WavePhase::Active { definition, .. } = &director.phase else {
+            continue;
+        };
+        if let Some(boss_id) = definition.boss.as_ref() {
+            pending_boss.0 = Some(BossSpawn {
+                id: boss_id.clone(),
+                wave: event.wave,
+            });
+        }
+    }
+}
+
+/// Brings in the pending boss at a spawn point once the population has room for it.
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_boss_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    boss_registry: Res<BossRegistry>,
+    enemy_registry: Res<EnemyRegistry>,
+    settings: Res<WaveSettings>,
+    director: Res<WaveDirector>,
+    mut pending_boss: ResMut<PendingBoss>,
+    mut population: ResMut<Population>,
+    mut spawn_points: ResMut<SpawnPoints>,
+    mut shake_events: EventWriter<CameraShakeEvent>,
+) {
+    let Some(pending) = pending_boss.0.as_ref() else {
+        return;
+    };
+    let (Some(boss), Some(archetype)) = (
+        boss_registry.get(&pending.id),
+        enemy_registry.get(&pending.id),
+    ) else {
+        warn!("Wave {} has an unknown boss {}", pending.wave, pending.id);
+        pending_boss.0 = None;
+        return;
+    };
+    let Some(first_phase) = boss.phases.first() else {
+        pending_boss.0 = None;
+        return;
+    };
+    if population.active >= settings.population.max_active_enemies {
+        return;
+    }
+    let mut rng = thread_rng();
+    let Some(position) = spawn_points.take(&mut rng) else {
+        return;
+    };
+
+    let health = archetype.health * settings.scaling.health_multiplier(director.run_seconds);
+    let entity = spawn_enemy(
+        &mut commands,
+        &asset_server,
+        archetype,
+        position.extend(BOSS_Z),
+    );
+    commands.entity(entity).insert((
+        Boss {
+            id: boss.id.clone(),
+            phase: 0,
+            max_health: health,
+            base_speed: archetype.speed,
+        },
+        BossIntro(Timer::from_seconds(boss.intro_seconds, TimerMode::Once)),
+        BossAttackState::idle(first_phase, &mut rng),
+        WaveMember(pending.wave),
+        EnemyHealth(health),
+    ));
+    population.active += 1;
+    shake_events.send(CameraShakeEvent(INTRO_SHAKE));
+    info!("{} appeared in wave {}", boss.name, pending.wave);
+    pending_boss.0 = None;
+}
+
+pub fn clear_pending_boss_system(mut pending_boss: ResMut<PendingBoss>) {
+    pending_boss.0 = None;
+}
+
+/// Keeps the boss in place during its entrance.
+pub fn boss_intro_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut boss_query: Query<(Entity, &mut BossIntro, &mut LinearVelocity)>,
+) {
+    for (entity, mut intro, mut velocity) in boss_query.iter_mut() {
+        velocity.0 = Vec2::ZERO;
+        if intro.0.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<BossIntro>();
+        }
+    }
+}
+
+/// Moves bosses on to their next phase once their health drops below its threshold.
+pub fn boss_phase_system(
+    registry: Res<BossRegistry>,
+    mut boss_query: Query<(&mut Boss, &EnemyHealth, &mut EnemyMovementSpeed)>,
+    mut shake_events: EventWriter<CameraShakeEvent>,
+) {
+    for (mut boss, health, mut movement_speed) in boss_query.iter_mut() {
+        let Some(definition) = registry.get(&boss.id) else {
+            continue;
+        };
+        let phase = definition.phase_at(health.0 / boss.max_health);
+        if phase > boss.phase {
+            boss.phase = phase;
+            info!("{} entered phase {}", definition.name, phase + 1);
+            shake_events.send(CameraShakeEvent(PHASE_SHAKE));
+        }
+        if let Some(phase) = definition.phases.get(boss.phase) {
+            movement_speed.0 = boss.base_speed * phase.speed_multiplier;
+        }
+    }
+}
+
+/// Runs the bosses' special attacks. Runs after the enemy movement so windups and charges
+/// can override the velocity the AI picked.
+pub fn boss_attack_system(
+    time: Res<Time>,
+    registry: Res<BossRegistry>,
+    mut gizmos: Gizmos,
+    mut boss_query: Query<
+        (
+            &Boss,
+            &Transform,
+            &mut BossAttackState,
+            &mut LinearVelocity,
+            &mut Sprite,
+            Has<HitFlash>,
+        ),
+        Without<BossIntro>,
+    >,
+    player_query: Query<&Transform, With<Player>>,
+    mut attack_events: EventWriter<BossAttackEvent>,
+) {
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+    let player_position = player_transform.translation.truncate();
+    let mut rng = thread_rng();
+
+    for (boss, transform, mut state, mut velocity, mut sprite, flashing) in boss_query.iter_mut() {
+        let Some(phase) = registry
+            .get(&boss.id)
+            .and_then(|definition| definition.phases.get(boss.phase))
+        else {
+            continue;
+        };
+        let position = transform.translation.truncate();
+
+        let was_winding_up = matches!(*state, BossAttackState::Windup { .. });
+        let released = state.update(
+            phase,
+            player_position - position,
+            time.delta_secs(),
+            &mut rng,
+        );
+        if let Some(attack) = released {
+            attack_events.send(BossAttackEvent { attack, position });
+        }
+        if let Some(forced_velocity) = state.velocity() {
+            velocity.0 = forced_velocity;
+        }
+
+        // The hit flash owns the sprite color while it lasts.
+        if let BossAttackState::Windup {
+            attack,
+            seconds_left,
+        } = &*state
+        {
+            if !flashing {
+                let pulse = (seconds_left * TELEGRAPH_PULSE * TAU).sin() * 0.5 + 0.5;
+                sprite.color = Color::WHITE.mix(&TELEGRAPH_COLOR, pulse);
+            }
+            if let BossAttack::Slam(slam) = attack {
+                gizmos.circle_2d(
+                    Isometry2d::from_translation(position),
+                    slam.radius,
+                    TELEGRAPH_COLOR,
+                );
+            }
+        } else if was_winding_up && !flashing {
+            sprite.color = Color::WHITE;
+        }
+    }
+}
+
+/// Telegraphs the minions of a summon attack around the boss, on walkable ground and as many
+/// as the population cap leaves room for.
+#[allow(clippy::too_many_arguments)]
+pub fn boss_summon_system(
+    mut commands: Commands,
+    registry: Res<EnemyRegistry>,
+    settings: Res<WaveSettings>,
+    telegraph_assets: Res<SpawnTelegraphAssets>,
+    ground_tiles: Res<GroundTiles>,
+    obstacle_tiles: Res<ObstacleTiles>,
+    mut population: ResMut<Population>,
+    mut attack_events: EventReader<BossAttackEvent>,
+) {
+    for event in attack_events.read() {
+        let BossAttack::Summon(summon) = &event.attack else {
+            continue;
+        };
+        let Some(archetype) = registry.get(&summon.archetype) else {
+            warn!("Boss summons an unknown archetype {}", summon.archetype);
+            continue;
+        };
+        for index in 0..summon.count {
+            if population.active >= settings.population.max_active_enemies {
+                break;
+            }
+            let angle = index as f32 / summon.count as f32 * TAU;
+            let position = event.position + Vec2::from_angle(angle) * summon.radius;
+            if !is_walkable(&ground_tiles, &obstacle_tiles, position) {
+                continue;
+            }
+            spawn_telegraph(
+                &mut commands,
+                &telegraph_assets,
+                &settings.population,
+                archetype,
+                archetype.health,
+                position,
+            );
+            population.active += 1;
+        }
+    }
+}
+
+/// Hits the player if they stand within a slam, through the same invulnerability window as
+/// other enemy damage.
+#[allow(clippy::too_many_arguments)]
+pub fn boss_slam_system(
+    mut commands: Commands,
+    explosive_assets: Res<ExplosiveAssets>,
+    mut effect_pool: ResMut<EffectPool>,
+    mut player_query: Query<
+        (
+            Entity,
+            &Transform,
+            &mut PlayerHealth,
+            Option<&DamageInvulnerability>,
+            Option<&PassiveAbility>,
+        ),
+        With<Player>,
+    >,
+    mut attack_events: EventReader<BossAttackEvent>,
+    mut hit_events: EventWriter<HitEvent>,
+    mut shake_events: EventWriter<CameraShakeEvent>,
+    mut noise_events: EventWriter<NoiseEvent>,
+) {
+    for event in attack_events.read() {
+        let BossAttack::Slam(slam) = &event.attack else {
+            continue;
+        };
+        play_effect(
+            &mut commands,
+            &mut effect_pool,
+            &explosive_assets.smoke.frames,
+            explosive_assets.smoke.frame_seconds,
+            Transform::from_translation(event.position.extend(EFFECT_Z))
+                .with_scale(Vec3::splat(SLAM_EFFECT_SCALE)),
+        );
+        shake_events.send(CameraShakeEvent(slam.camera_shake));
+        noise_events.send(NoiseEvent {
+            position: event.position,
+            radius: SLAM_NOISE_RADIUS,
+        });
+
+        let Ok((player_entity, player_transform, mut player_health, invulnerability, passive)) =
+            player_query.get_single_mut()
+        else {
+            continue;
+        };
+        let offset = player_transform.translation.truncate() - event.position;
+        let invulnerable =
+            invulnerability.is_some_and(|invulnerability| !invulnerability.timer.finished());
+        if offset.length() > slam.radius || invulnerable {
+            continue;
+        }
+        player_health.decrease_health(slam.damage);
+        hit_events.send(HitEvent {
+            target: player_entity,
+            direction: offset,
+            force: slam.knockback,
+        });
+        commands
+            .entity(player_entity)
+            .insert(PassiveAbility::damage_invulnerability(passive));
+    }
+}
+
+/// Announces defeated bosses and ends the run if the boss was its finale. Runs before the
+/// enemy is despawned.
+pub fn boss_defeat_system(
+    registry: Res<BossRegistry>,
+    boss_query: Query<&Boss>,
+    mut killed_events: EventReader<EnemyKilledEvent>,
+    mut defeated_events: EventWriter<BossDefeatedEvent>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for event in killed_events.read() {
+        let Ok(boss) = boss_query.get(event.enemy) else {
+            continue;
+        };
+        let Some(definition) = registry.get(&boss.id) else {
+            continue;
+        };
+        info!("{} was defeated", definition.name);
+        defeated_events.send(BossDefeatedEvent {
+            name: definition.name.clone(),
+        });
+        if definition.on_defeat == BossDefeat::WinRun {
+            next_state.set(AppState::GameWon);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        enemy::{
+            components::EnemyType,
+            resources::EnemyKillCount,
+            systems::{despawn_killed_enemies_system, kill_enemy_system},
+        },
+        hit_feedback::events::DamageEvent,
+    };
+    use bevy::state::app::StatesPlugin;
+
+    #[test]
+    fn test_killing_the_final_boss_wins_the_run() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_state::<AppState>()
+            .add_event::<DamageEvent>()
+            .add_event::<EnemyKilledEvent>()
+            .add_event::<BossDefeatedEvent>()
+            .insert_resource(EnemyKillCount(0))
+            .insert_resource(BossRegistry::load())
+            .add_systems(
+                Update,
+                (
+                    kill_enemy_system,
+                    // Any system with commands after the kill adds a sync point in between.
+                    (|mut commands: Commands| {
+                        commands.spawn_empty();
+                    })
+                    .after(kill_enemy_system),
+                    boss_defeat_system
+                        .after(kill_enemy_system)
+                        .before(despawn_killed_enemies_system),
+                    despawn_killed_enemies_system.after(kill_enemy_system),
+                ),
+            );
+        let boss = app
+            .world_mut()
+            .spawn((
+                Boss {
+                    id: "zombie_king".to_string(),
+                    phase: 0,
+                    max_health: 100.0,
+                    base_speed: 50.0,
+                },
+                EnemyHealth(0.0),
+                EnemyType("zombie_king".to_string()),
+                Transform::default(),
+            ))
+            .id();
+
+        app.update();
+
+        assert!(matches!(
+            app.world().resource::<NextState<AppState>>(),
+            NextState::Pending(AppState::GameWon)
+        ));
+        assert!(app.world().get_entity(boss).is_err());
+    }
+}
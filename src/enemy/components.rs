@@ -1,27 +1,25 @@
 use bevy::prelude::*;
-use std::collections::HashMap;
+use serde::Deserialize;
+
+use crate::status_effects::components::StatusEffects;
 
 #[derive(Component)]
+#[require(StatusEffects)]
 pub struct Enemy;
 
 #[derive(Component, Debug)]
 pub struct EnemyHealth(pub f32);
 
-#[derive(Component)]
-pub enum EnemyType {
-    Zombie { speed: f32 },
-    Skeleton { speed: f32 },
-    Boss { speed: f32 },
-}
+/// Id of the enemy's archetype in the `EnemyRegistry`.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct EnemyType(pub String);
 
-#[derive(Component, Resource)]
-pub struct SpawnTimer(pub Timer);
+#[derive(Component, Debug)]
+pub struct EnemyMovementSpeed(pub f32);
 
-#[derive(Resource)]
-pub struct WaveTimer {
-    pub timer: Timer,
-    pub wave: u32,
-}
+/// Hearts the player loses when touched by the enemy.
+#[derive(Component, Debug)]
+pub struct ContactDamage(pub u8);
 
 #[derive(Component)]
 pub struct AnimationTimer(pub Timer);
@@ -29,14 +27,7 @@ pub struct AnimationTimer(pub Timer);
 #[derive(Component)]
 pub struct AnimationFrame(pub u8);
 
-#[derive(Resource)]
-pub struct EnemyAnimationFrames {
-    pub zombie: HashMap<FacingDirection, Vec<Handle<Image>>>,
-    pub skeleton: HashMap<FacingDirection, Vec<Handle<Image>>>,
-    pub boss: HashMap<FacingDirection, Vec<Handle<Image>>>,
-}
-
-#[derive(Component, PartialEq, Eq, Debug, Clone, Copy, Hash)]
+#[derive(Component, PartialEq, Eq, Debug, Clone, Copy, Hash, Deserialize)]
 pub enum FacingDirection {
     Up,
     Down,
@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// What a killed enemy leaves on the ground.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+pub enum Remains {
+    #[default]
+    BloodStain,
+    Corpse,
+}
+
+/// Body of a killed enemy. It has no collider or AI, it only topples over and darkens before
+/// it leaves its remains behind.
+#[derive(Component, Debug)]
+pub struct DyingBody {
+    pub timer: Timer,
+    pub remains: Remains,
+    /// Scale of the remains, so bigger enemies leave bigger stains.
+    pub remains_scale: f32,
+    /// Rotation the body topples over to, in radians.
+    pub fall_angle: f32,
+}
+
+/// A corpse or blood stain on the ground that fades out at the end of its lifetime.
+#[derive(Component, Debug)]
+pub struct Decal {
+    pub chunk: (i32, i32),
+    pub lifetime: Timer,
+}
+
+/// Flies buzzing around a corpse.
+#[derive(Component, Debug)]
+pub struct CorpseFlies {
+    pub variant: usize,
+    pub frame: usize,
+    pub frame_timer: Timer,
+}
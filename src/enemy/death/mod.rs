@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use resources::ChunkDecals;
+use systems::*;
+
+use crate::{map_genreation::resources::CurrentChunks, AppState};
+
+use super::systems::{despawn_killed_enemies_system, kill_enemy_system};
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+/// Death animations of killed enemies and the corpses and blood stains they leave behind.
+pub struct DeathPlugin;
+
+impl Plugin for DeathPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ChunkDecals>()
+            .add_systems(Startup, load_death_assets)
+            .add_systems(
+                Update,
+                (
+                    start_death_system
+                        .after(kill_enemy_system)
+                        .before(despawn_killed_enemies_system),
+                    dying_body_system,
+                    corpse_flies_system,
+                    decal_lifetime_system,
+                    despawn_unloaded_decals_system.run_if(resource_changed::<CurrentChunks>),
+                )
+                    .run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(OnExit(AppState::InGame), despawn_remains_system);
+    }
+}
@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use std::collections::{HashMap, VecDeque};
+
+/// Decals kept per chunk, the oldest goes when a new one would exceed this.
+pub const MAX_DECALS_PER_CHUNK: usize = 24;
+
+#[derive(Resource)]
+pub struct DeathAssets {
+    pub blood_splash_frames: Vec<Handle<Image>>,
+    pub blood_stains: Vec<Handle<Image>>,
+    /// Fly animation frames of every corpse variant.
+    pub corpses: Vec<Vec<Handle<Image>>>,
+}
+
+/// Decals on the ground by chunk, oldest first.
+#[derive(Resource, Debug, Default)]
+pub struct ChunkDecals(HashMap<(i32, i32), VecDeque<Entity>>);
+
+impl ChunkDecals {
+    /// Adds a decal to its chunk. Returns the decal that has to go to stay within
+    /// `MAX_DECALS_PER_CHUNK`.
+    pub fn add(&mut self, chunk: (i32, i32), decal: Entity) -> Option<Entity> {
+        let decals = self.0.entry(chunk).or_default();
+        decals.push_back(decal);
+        if decals.len() > MAX_DECALS_PER_CHUNK {
+            decals.pop_front()
+        } else {
+            None
+        }
+    }
+
+    pub fn remove(&mut self, chunk: (i32, i32), decal: Entity) {
+        if let Some(decals) = self.0.get_mut(&chunk) {
+            decals.retain(|entity| *entity != decal);
+            if decals.is_empty() {
+                self.0.remove(&chunk);
+            }
+        }
+    }
+
+    /// Forgets the decals of every chunk that is no longer loaded and returns them.
+    pub fn take_unloaded(&mut self, loaded: impl Fn(&(i32, i32)) -> bool) -> Vec<Entity> {
+        let mut unloaded = Vec::new();
+        self.0.retain(|chunk, decals| {
+            if loaded(chunk) {
+                return true;
+            }
+            unloaded.extend(decals.drain(..));
+            false
+        });
+        unloaded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oldest_decal_goes_once_a_chunk_is_full() {
+        let mut decals = ChunkDecals::default();
+        let entities: Vec<Entity> = (0..=MAX_DECALS_PER_CHUNK as u32)
+            .map(Entity::from_raw)
+            .collect();
+
+        for entity in &entities[..MAX_DECALS_PER_CHUNK] {
+            assert_eq!(decals.add((0, 0), *entity), None);
+        }
+        // Other chunks have their own limit.
+        assert_eq!(decals.add((1, 0), entities[0]), None);
+        assert_eq!(
+            decals.add((0, 0), entities[MAX_DECALS_PER_CHUNK]),
+            Some(entities[0])
+        );
+
+        decals.remove((0, 0), entities[1]);
+        assert_eq!(decals.add((0, 0), entities[0]), None);
+    }
+
+    #[test]
+    fn test_unloaded_chunks_give_up_their_decals() {
+        let mut decals = ChunkDecals::default();
+        decals.add((0, 0), Entity::from_raw(1));
+        decals.add((0, 0), Entity::from_raw(2));
+        decals.add((5, 5), Entity::from_raw(3));
+
+        let unloaded = decals.take_unloaded(|chunk| *chunk == (5, 5));
+
+        assert_eq!(unloaded, vec![Entity::from_raw(1), Entity::from_raw(2)]);
+        assert!(decals.take_unloaded(|chunk| *chunk == (5, 5)).is_empty());
+        assert_eq!(decals.take_unloaded(|_| false), vec![Entity::from_raw(3)]);
+    }
+}
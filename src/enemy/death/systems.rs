@@ -0,0 +1,226 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use std::f32::consts::FRAC_PI_2;
+
+use crate::{
+    enemy::{components::Enemy, events::EnemyKilledEvent, resources::EnemyRegistry},
+    map_genreation::{resources::CurrentChunks, util::world_to_chunk},
+    player::weapon::pool::{play_effect, EffectPool},
+    sprites::frame_path,
+};
+
+use super::{
+    components::{CorpseFlies, Decal, DyingBody, Remains},
+    resources::{ChunkDecals, DeathAssets},
+};
+
+const BLOOD_SPLASH_FOLDER: &str = "Blood Animation Frames";
+const BLOOD_SPLASH_FRAMES: std::ops::RangeInclusive<u32> = 379..=383;
+const BLOOD_STAIN_FOLDER: &str = "Random Blood Stains";
+const BLOOD_STAIN_FRAMES: std::ops::RangeInclusive<u32> = 384..=388;
+const CORPSE_FOLDER: &str = "Dead Corpses With Flies Animation Frames";
+/// The sheet holds two corpses with three fly frames each.
+const CORPSE_FRAMES: [[u32; 3]; 2] = [[290, 293, 295], [291, 292, 294]];
+
+const DEATH_SECONDS: f32 = 0.45;
+const BLOOD_SPLASH_FRAME_SECONDS: f32 = 0.07;
+const FLY_FRAME_SECONDS: f32 = 0.15;
+const DECAL_LIFETIME_SECONDS: f32 = 30.0;
+/// Decals fade out over the last seconds of their lifetime.
+const DECAL_FADE_SECONDS: f32 = 5.0;
+const DEATH_COLOR: Color = Color::srgb(0.45, 0.2, 0.2);
+/// Enemy sprite size the remains are drawn at their own size for.
+const REMAINS_BASE_SIZE: f32 = 16.0;
+const DECAL_Z: f32 = 2.5;
+const EFFECT_Z: f32 = 10.0;
+
+pub fn load_death_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(DeathAssets {
+        blood_splash_frames: BLOOD_SPLASH_FRAMES
+            .map(|frame| asset_server.load(frame_path(BLOOD_SPLASH_FOLDER, frame)))
+            .collect(),
+        blood_stains: BLOOD_STAIN_FRAMES
+            .map(|frame| asset_server.load(frame_path(BLOOD_STAIN_FOLDER, frame)))
+            .collect(),
+        corpses: CORPSE_FRAMES
+            .iter()
+            .map(|frames| {
+                frames
+                    .iter()
+                    .map(|frame| asset_server.load(frame_path(CORPSE_FOLDER, *frame)))
+                    .collect()
+            })
+            .collect(),
+    });
+}
+
+/// Leaves a dying body and a blood splash behind every killed enemy. Runs before the
+/// despawn of the enemy is applied, so the body can take over its sprite.
+pub fn start_death_system(
+    mut commands: Commands,
+    registry: Res<EnemyRegistry>,
+    assets: Res<DeathAssets>,
+    mut effect_pool: ResMut<EffectPool>,
+    mut killed_events: EventReader<EnemyKilledEvent>,
+    enemy_query: Query<(&Transform, &Sprite), With<Enemy>>,
+) {
+    for event in killed_events.read() {
+        let Ok((transform, sprite)) = enemy_query.get(event.enemy) else {
+            continue;
+        };
+        let Some(archetype) = registry.get(&event.archetype) else {
+            continue;
+        };
+        let remains_scale = archetype.sprite_size / REMAINS_BASE_SIZE;
+
+        commands.spawn((
+            DyingBody {
+                timer: Timer::from_seconds(DEATH_SECONDS, TimerMode::Once),
+                remains: archetype.remains,
+                remains_scale,
+                fall_angle: if sprite.flip_x { -FRAC_PI_2 } else { FRAC_PI_2 },
+            },
+            Sprite {
+                image: sprite.image.clone(),
+                custom_size: sprite.custom_size,
+                flip_x: sprite.flip_x,
+                ..default()
+            },
+            Transform::from_translation(transform.translation),
+            Name::new("DyingBody"),
+        ));
+        play_effect(
+            &mut commands,
+            &mut effect_pool,
+            &assets.blood_splash_frames,
+            BLOOD_SPLASH_FRAME_SECONDS,
+            Transform::from_translation(transform.translation.truncate().extend(EFFECT_Z))
+                .with_scale(Vec3::splat(remains_scale)),
+        );
+    }
+}
+
+/// Topples dying bodies over and replaces them with their remains once they hit the ground.
+pub fn dying_body_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    assets: Res<DeathAssets>,
+    mut chunk_decals: ResMut<ChunkDecals>,
+    mut body_query: Query<(Entity, &mut DyingBody, &mut Transform, &mut Sprite)>,
+) {
+    let mut rng = thread_rng();
+
+    for (entity, mut body, mut transform, mut sprite) in body_query.iter_mut() {
+        let progress = body.timer.tick(time.delta()).fraction();
+        transform.rotation = Quat::from_rotation_z(body.fall_angle * progress);
+        sprite.color = Color::WHITE.mix(&DEATH_COLOR, progress);
+        if !body.timer.finished() {
+            continue;
+        }
+        commands.entity(entity).despawn();
+
+        let position = transform.translation.truncate();
+        let chunk = world_to_chunk(position.x, position.y);
+        let mut decal = commands.spawn((
+            Decal {
+                chunk,
+                lifetime: Timer::from_seconds(DECAL_LIFETIME_SECONDS, TimerMode::Once),
+            },
+            Transform::from_translation(position.extend(DECAL_Z))
+                .with_scale(Vec3::splat(body.remains_scale)),
+            Name::new("Decal"),
+        ));
+        match body.remains {
+            Remains::BloodStain => {
+                decal.insert(Sprite {
+                    image: assets
+                        .blood_stains
+                        .choose(&mut rng)
+                        .cloned()
+                        .unwrap_or_default(),
+                    flip_x: rng.gen(),
+                    ..default()
+                });
+            }
+            Remains::Corpse => {
+                let variant = rng.gen_range(0..assets.corpses.len());
+                decal.insert((
+                    Sprite {
+                        image: assets.corpses[variant][0].clone(),
+                        flip_x: body.fall_angle < 0.0,
+                        ..default()
+                    },
+                    CorpseFlies {
+                        variant,
+                        frame: 0,
+                        frame_timer: Timer::from_seconds(FLY_FRAME_SECONDS, TimerMode::Repeating),
+                    },
+                ));
+            }
+        }
+
+        let decal = decal.id();
+        if let Some(oldest) = chunk_decals.add(chunk, decal) {
+            commands.entity(oldest).despawn();
+        }
+    }
+}
+
+pub fn corpse_flies_system(
+    time: Res<Time>,
+    assets: Res<DeathAssets>,
+    mut corpse_query: Query<(&mut CorpseFlies, &mut Sprite)>,
+) {
+    for (mut flies, mut sprite) in corpse_query.iter_mut() {
+        if !flies.frame_timer.tick(time.delta()).just_finished() {
+            continue;
+        }
+        let frames = &assets.corpses[flies.variant];
+        flies.frame = (flies.frame + 1) % frames.len();
+        sprite.image = frames[flies.frame].clone();
+    }
+}
+
+/// Fades decals out at the end of their lifetime.
+pub fn decal_lifetime_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut chunk_decals: ResMut<ChunkDecals>,
+    mut decal_query: Query<(Entity, &mut Decal, &mut Sprite)>,
+) {
+    for (entity, mut decal, mut sprite) in decal_query.iter_mut() {
+        decal.lifetime.tick(time.delta());
+        if decal.lifetime.finished() {
+            chunk_decals.remove(decal.chunk, entity);
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let alpha = (decal.lifetime.remaining_secs() / DECAL_FADE_SECONDS).min(1.0);
+        sprite.color.set_alpha(alpha);
+    }
+}
+
+/// Despawns the decals of chunks the terrain unloaded.
+pub fn despawn_unloaded_decals_system(
+    mut commands: Commands,
+    current_chunks: Res<CurrentChunks>,
+    mut chunk_decals: ResMut<ChunkDecals>,
+) {
+    for decal in chunk_decals.take_unloaded(|chunk| current_chunks.0.contains_key(chunk)) {
+        if let Some(mut entity_commands) = commands.get_entity(decal) {
+            entity_commands.despawn();
+        }
+    }
+}
+
+pub fn despawn_remains_system(
+    mut commands: Commands,
+    mut chunk_decals: ResMut<ChunkDecals>,
+    decal_query: Query<Entity, With<Decal>>,
+    body_query: Query<Entity, With<DyingBody>>,
+) {
+    chunk_decals.take_unloaded(|_| false);
+    for entity in decal_query.iter().chain(body_query.iter()) {
+        commands.entity(entity).despawn();
+    }
+}
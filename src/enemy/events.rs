@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+use crate::hit_feedback::events::DamageSource;
+
+/// Sent when an enemy dies. The enemy is despawned by `despawn_killed_enemies_system`, systems
+/// ordered before it can still look it up.
+#[derive(Event, Debug, Clone)]
+pub struct EnemyKilledEvent {
+    pub enemy: Entity,
+    /// Entity that dealt the final blow, if it is known.
+    pub killer: Option<Entity>,
+    pub position: Vec2,
+    /// Id of the enemy's archetype in the `EnemyRegistry`.
+    pub archetype: String,
+    /// `None` if the enemy lost its health without a damage event, like with the debug kill key.
+    pub source: Option<DamageSource>,
+}
@@ -1,34 +1,53 @@
+use ai::AiPlugin;
 use bevy::prelude::*;
-use components::*;
-use resources::EnemyKillCount;
+use boss::BossPlugin;
+use death::DeathPlugin;
+use events::EnemyKilledEvent;
+use population::PopulationPlugin;
+use ranged::RangedPlugin;
+use resources::{EnemyKillCount, EnemyRegistry};
+use spatial::SpatialHashPlugin;
 use systems::*;
+use waves::WavesPlugin;
 
 use crate::AppState;
 
+pub mod ai;
+pub mod boss;
 pub mod components;
+pub mod death;
+pub mod events;
+pub mod population;
+pub mod ranged;
 pub mod resources;
-mod systems;
+pub mod spatial;
+pub mod systems;
+pub mod waves;
 
 pub struct EnemyPlugin;
 
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(SpawnTimer(Timer::from_seconds(2.0, TimerMode::Repeating)))
-            .insert_resource(WaveTimer {
-                timer: Timer::from_seconds(60.0, TimerMode::Repeating),
-                wave: 0,
-            })
+        app.add_plugins((
+            WavesPlugin,
+            AiPlugin,
+            SpatialHashPlugin,
+            RangedPlugin,
+            BossPlugin,
+            DeathPlugin,
+            PopulationPlugin,
+        ));
+        app.add_event::<EnemyKilledEvent>()
             .insert_resource(EnemyKillCount(0))
+            .insert_resource(EnemyRegistry::load())
             .add_systems(Startup, setup_enemy_sprites)
+            .add_systems(OnEnter(AppState::InGame), reset_kill_count_system)
             .add_systems(
                 Update,
                 (
-                    spawn_enemy_system,
-                    enemy_movement_and_direction_system,
+                    (enemy_movement_and_direction_system, enemy_separation_system).chain(),
                     animate_enemy_system,
-                    prevent_enemy_overlap_system,
-                    kill_enemy_system,
-                    wave_timer_system,
+                    (kill_enemy_system, despawn_killed_enemies_system).chain(),
                 )
                     .run_if(in_state(AppState::InGame)),
             );
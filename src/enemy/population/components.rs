@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+
+/// A shadow on the ground where an enemy is about to appear.
+#[derive(Component, Debug)]
+pub struct SpawnTelegraph {
+    /// Id of the enemy's archetype in the `EnemyRegistry`.
+    pub archetype: String,
+    pub health: f32,
+    /// Full size of the shadow, it grows into it while the timer runs.
+    pub size: Vec2,
+    pub timer: Timer,
+}
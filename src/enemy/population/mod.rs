@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+use resources::{Population, SpawnPoints};
+use systems::*;
+
+use crate::AppState;
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+pub use systems::{is_walkable, spawn_telegraph};
+
+/// Keeps the number of enemies in check, spawns them just outside the view behind a telegraph
+/// and brings back enemies the player left far behind.
+pub struct PopulationPlugin;
+
+impl Plugin for PopulationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Population>()
+            .init_resource::<SpawnPoints>()
+            .add_systems(Startup, load_spawn_telegraph_assets)
+            .add_systems(
+                PreUpdate,
+                (count_population_system, update_spawn_points_system)
+                    .run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(
+                Update,
+                (spawn_telegraph_system, recycle_enemies_system).run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(OnExit(AppState::InGame), despawn_telegraphs_system);
+    }
+}
@@ -0,0 +1,146 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use serde::Deserialize;
+
+/// Points around the edge of the view tried when looking for spawn points.
+const SPAWN_POINT_SAMPLES: usize = 24;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct PopulationSettings {
+    /// Enemies and pending spawns there can be at once, waves hold back their spawns above it.
+    pub max_active_enemies: usize,
+    /// How far outside the camera view enemies spawn.
+    pub spawn_margin: f32,
+    /// A spawn telegraph waits while the player is closer than this.
+    pub min_spawn_distance: f32,
+    /// Enemies further than this outside the spawn points are moved to one of them. The
+    /// spawn points follow the view, so this grows with the zoom.
+    pub recycle_margin: f32,
+    pub telegraph_seconds: f32,
+}
+
+/// Enemies and pending spawns, counted at the start of every frame. Spawners add what they
+/// spawn, so several of them in one frame can't go past the cap together.
+#[derive(Resource, Debug, Default)]
+pub struct Population {
+    pub active: usize,
+}
+
+/// Walkable points just outside the camera view, refreshed every frame.
+#[derive(Resource, Debug, Default)]
+pub struct SpawnPoints {
+    pub points: Vec<Vec2>,
+    /// The view grown by the spawn margin, the spawn points lie on its edge.
+    pub area: Rect,
+}
+
+impl SpawnPoints {
+    /// Samples the edge of `view` grown by `margin` and keeps the points on ground `walkable`
+    /// allows.
+    pub fn sample(
+        view: Rect,
+        margin: f32,
+        walkable: impl Fn(Vec2) -> bool,
+        rng: &mut impl Rng,
+    ) -> Self {
+        let area = view.inflate(margin);
+        let (width, height) = (area.width(), area.height());
+        let perimeter = 2.0 * (width + height);
+        if perimeter <= 0.0 {
+            return Self {
+                points: Vec::new(),
+                area,
+            };
+        }
+
+        let points = (0..SPAWN_POINT_SAMPLES)
+            .map(|_| {
+                let along = rng.gen_range(0.0..perimeter);
+                if along < width {
+                    Vec2::new(area.min.x + along, area.max.y)
+                } else if along < width + height {
+                    Vec2::new(area.max.x, area.max.y - (along - width))
+                } else if along < 2.0 * width + height {
+                    Vec2::new(area.max.x - (along - width - height), area.min.y)
+                } else {
+                    Vec2::new(area.min.x, area.min.y + (along - 2.0 * width - height))
+                }
+            })
+            .filter(|point| walkable(*point))
+            .collect();
+        Self { points, area }
+    }
+
+    /// Takes a random spawn point, so two spawns in the same frame don't share one.
+    pub fn take(&mut self, rng: &mut impl Rng) -> Option<Vec2> {
+        if self.points.is_empty() {
+            return None;
+        }
+        let index = rng.gen_range(0..self.points.len());
+        Some(self.points.swap_remove(index))
+    }
+
+    /// Whether `position` lies more than `margin` outside the spawn points, so moving an enemy
+    /// from there to one of them brings it closer.
+    pub fn out_of_reach(&self, position: Vec2, margin: f32) -> bool {
+        !self.area.inflate(margin).contains(position)
+    }
+}
+
+#[derive(Resource)]
+pub struct SpawnTelegraphAssets {
+    pub shadow: Handle<Image>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_spawn_points_are_outside_the_view() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let view = Rect::new(-100.0, -50.0, 100.0, 50.0);
+        let points = SpawnPoints::sample(view, 20.0, |_| true, &mut rng);
+
+        assert_eq!(points.points.len(), SPAWN_POINT_SAMPLES);
+        for point in points.points.iter() {
+            assert!(!view.contains(*point), "{:?} is in view", point);
+            assert!(
+                view.inflate(20.5).contains(*point),
+                "{:?} is too far",
+                point
+            );
+        }
+    }
+
+    #[test]
+    fn test_spawn_points_stay_on_walkable_ground() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let view = Rect::new(-100.0, -50.0, 100.0, 50.0);
+        // Only the right side of the view is walkable.
+        let mut points = SpawnPoints::sample(view, 20.0, |point| point.x > 110.0, &mut rng);
+
+        assert!(!points.points.is_empty());
+        while let Some(point) = points.take(&mut rng) {
+            assert!(point.x > 110.0);
+        }
+        assert!(SpawnPoints::sample(view, 20.0, |_| false, &mut rng)
+            .take(&mut rng)
+            .is_none());
+    }
+
+    #[test]
+    fn test_spawn_points_are_never_out_of_reach() {
+        let mut rng = StdRng::seed_from_u64(5);
+        // A view zoomed out far enough to be wider than any fixed recycle distance.
+        let view = Rect::new(-3200.0, -1800.0, 3200.0, 1800.0);
+        let points = SpawnPoints::sample(view, 48.0, |_| true, &mut rng);
+
+        for point in points.points.iter() {
+            assert!(!points.out_of_reach(*point, 1.0));
+        }
+        assert!(points.out_of_reach(Vec2::new(3300.0, 0.0), 1.0));
+        assert!(!points.out_of_reach(Vec2::ZERO, 1.0));
+    }
+}
@@ -0,0 +1,261 @@
+use avian2d::prelude::*;
+use bevy::{
+    prelude::*,
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+    },
+};
+use rand::prelude::*;
+
+use crate::{
+    enemy::{
+        ai::components::{Awareness, EnemyAi},
+        components::{Enemy, EnemyHealth},
+        resources::{EnemyArchetype, EnemyRegistry},
+        systems::spawn_enemy,
+        waves::{components::WaveMember, resources::WaveSettings},
+    },
+    explosives::resources::ExplosiveAssets,
+    map_genreation::{
+        resources::{GroundTiles, ObstacleTiles},
+        util::world_to_tile,
+    },
+    player::{
+        components::{Player, PlayerCamera},
+        weapon::pool::{play_effect, EffectPool},
+    },
+};
+
+use super::{
+    components::SpawnTelegraph,
+    resources::{Population, PopulationSettings, SpawnPoints, SpawnTelegraphAssets},
+};
+
+const SHADOW_TEXTURE_SIZE: u32 = 32;
+/// Width of the telegraph shadow relative to the enemy's hitbox, the shadow is half as tall.
+const SHADOW_WIDTH_PER_HITBOX: f32 = 1.8;
+const SPAWN_DUST_SCALE: f32 = 1.5;
+const TELEGRAPH_Z: f32 = 2.6;
+const ENEMY_Z: f32 = 10.0;
+const EFFECT_Z: f32 = 10.0;
+
+/// A soft black circle that fades out towards its edge.
+fn shadow_image() -> Image {
+    let size = SHADOW_TEXTURE_SIZE;
+    let mut data = Vec::with_capacity((size * size * 4) as usize);
+    for y in 0..size {
+        for x in 0..size {
+            let offset = (Vec2::new(x as f32, y as f32) + 0.5) / size as f32 * 2.0 - 1.0;
+            let alpha = (1.0 - offset.length()).clamp(0.0, 1.0).sqrt();
+            data.extend_from_slice(&[0, 0, 0, (alpha * 255.0) as u8]);
+        }
+    }
+    Image::new(
+        Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+}
+
+pub fn load_spawn_telegraph_assets(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    commands.insert_resource(SpawnTelegraphAssets {
+        shadow: images.add(shadow_image()),
+    });
+}
+
+pub fn count_population_system(
+    mut population: ResMut<Population>,
+    enemy_query: Query<(), With<Enemy>>,
+    telegraph_query: Query<(), With<SpawnTelegraph>>,
+) {
+    population.active = enemy_query.iter().count() + telegraph_query.iter().count();
+}
+
+/// Whether `position` is on loaded ground without an obstacle.
+pub fn is_walkable(
+    ground_tiles: &GroundTiles,
+    obstacle_tiles: &ObstacleTiles,
+    position: Vec2,
+) -> bool {
+    let tile = world_to_tile(position.x, position.y);
+    ground_tiles.0.contains(&tile) && !obstacle_tiles.0.contains(&tile)
+}
+
+/// Looks for walkable ground just outside what the camera currently shows, so zooming out
+/// pushes the spawn points out with the view.
+pub fn update_spawn_points_system(
+    mut spawn_points: ResMut<SpawnPoints>,
+    settings: Res<WaveSettings>,
+    ground_tiles: Res<GroundTiles>,
+    obstacle_tiles: Res<ObstacleTiles>,
+    camera_query: Query<(&Transform, &OrthographicProjection), With<PlayerCamera>>,
+) {
+    let Ok((camera_transform, projection)) = camera_query.get_single() else {
+        spawn_points.points.clear();
+        return;
+    };
+    let scale = camera_transform.scale.truncate();
+    let view = Rect::from_center_size(
+        camera_transform.translation.truncate() + projection.area.center() * scale,
+        projection.area.size() * scale,
+    );
+    *spawn_points = SpawnPoints::sample(
+        view,
+        settings.population.spawn_margin,
+        |point| is_walkable(&ground_tiles, &obstacle_tiles, point),
+        &mut thread_rng(),
+    );
+}
+
+/// Puts down a spawn telegraph, the enemy appears once it has run its course.
+pub fn spawn_telegraph(
+    commands: &mut Commands,
+    assets: &SpawnTelegraphAssets,
+    settings: &PopulationSettings,
+    archetype: &EnemyArchetype,
+    health: f32,
+    position: Vec2,
+) -> Entity {
+    let width = archetype.hitbox.0 * SHADOW_WIDTH_PER_HITBOX;
+    commands
+        .spawn((
+            SpawnTelegraph {
+                archetype: archetype.id.clone(),
+                health,
+                size: Vec2::new(width, width * 0.5),
+                timer: Timer::from_seconds(settings.telegraph_seconds, TimerMode::Once),
+            },
+            Sprite {
+                image: assets.shadow.clone(),
+                custom_size: Some(Vec2::ZERO),
+                ..default()
+            },
+            Transform::from_translation(position.extend(TELEGRAPH_Z)),
+            Name::new("SpawnTelegraph"),
+        ))
+        .id()
+}
+
+/// Grows the telegraph shadows and brings in their enemies, which start out hunting the player
+/// since they appear out of sight. A finished telegraph waits while the player stands too
+/// close, so enemies never appear right next to them.
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_telegraph_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    registry: Res<EnemyRegistry>,
+    settings: Res<WaveSettings>,
+    explosive_assets: Res<ExplosiveAssets>,
+    mut effect_pool: ResMut<EffectPool>,
+    player_query: Query<&Transform, With<Player>>,
+    mut telegraph_query: Query<(
+        Entity,
+        &mut SpawnTelegraph,
+        &mut Sprite,
+        &Transform,
+        Option<&WaveMember>,
+    )>,
+) {
+    let player_position = player_query
+        .get_single()
+        .map(|transform| transform.translation.truncate())
+        .ok();
+
+    for (entity, mut telegraph, mut sprite, transform, wave_member) in telegraph_query.iter_mut() {
+        let progress = telegraph.timer.tick(time.delta()).fraction();
+        sprite.custom_size = Some(telegraph.size * progress);
+        if !telegraph.timer.finished() {
+            continue;
+        }
+        let position = transform.translation.truncate();
+        if player_position.is_some_and(|player_position| {
+            player_position.distance(position) < settings.population.min_spawn_distance
+        }) {
+            continue;
+        }
+
+        commands.entity(entity).despawn();
+        let Some(archetype) = registry.get(&telegraph.archetype) else {
+            continue;
+        };
+        let enemy = spawn_enemy(
+            &mut commands,
+            &asset_server,
+            archetype,
+            position.extend(ENEMY_Z),
+        );
+        commands.entity(enemy).insert(EnemyHealth(telegraph.health));
+        if let Some(player_position) = player_position {
+            commands
+                .entity(enemy)
+                .insert((Awareness::hunting(player_position), EnemyAi::hunting()));
+        }
+        if let Some(wave_member) = wave_member {
+            commands.entity(enemy).insert(WaveMember(wave_member.0));
+        }
+        play_effect(
+            &mut commands,
+            &mut effect_pool,
+            &explosive_assets.smoke.frames,
+            explosive_assets.smoke.frame_seconds,
+            Transform::from_translation(position.extend(EFFECT_Z))
+                .with_scale(Vec3::splat(SPAWN_DUST_SCALE)),
+        );
+    }
+}
+
+/// Moves enemies the player left far behind to a spawn point near the player again, back on
+/// the hunt.
+pub fn recycle_enemies_system(
+    settings: Res<WaveSettings>,
+    mut spawn_points: ResMut<SpawnPoints>,
+    player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
+    mut enemy_query: Query<
+        (
+            &mut Transform,
+            &mut LinearVelocity,
+            &mut Awareness,
+            &mut EnemyAi,
+        ),
+        With<Enemy>,
+    >,
+) {
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+    let player_position = player_transform.translation.truncate();
+    let mut rng = thread_rng();
+
+    for (mut transform, mut velocity, mut awareness, mut ai) in enemy_query.iter_mut() {
+        if !spawn_points.out_of_reach(
+            transform.translation.truncate(),
+            settings.population.recycle_margin,
+        ) {
+            continue;
+        }
+        let Some(spawn_point) = spawn_points.take(&mut rng) else {
+            return;
+        };
+        transform.translation = spawn_point.extend(transform.translation.z);
+        velocity.0 = Vec2::ZERO;
+        *awareness = Awareness::hunting(player_position);
+        *ai = EnemyAi::hunting();
+    }
+}
+
+pub fn despawn_telegraphs_system(
+    mut commands: Commands,
+    telegraph_query: Query<Entity, With<SpawnTelegraph>>,
+) {
+    for entity in telegraph_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
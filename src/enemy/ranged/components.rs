@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::status_effects::components::StatusEffect;
+
+/// Lets an enemy fire a projectile whenever its AI releases an attack.
+#[derive(Component, Debug, Clone, Copy, Deserialize)]
+pub struct RangedAttack {
+    pub projectile_speed: f32,
+    /// Hearts the player loses when hit.
+    pub damage: u8,
+    /// Distance after which the projectile splashes on the ground.
+    pub max_distance: f32,
+    pub projectile_radius: f32,
+    #[serde(default)]
+    pub status_effect: Option<StatusEffect>,
+}
+
+/// A projectile fired by an enemy. It flies straight, so the player can step out of its way.
+#[derive(Component, Debug)]
+pub struct EnemyProjectile {
+    pub damage: u8,
+    pub status_effect: Option<StatusEffect>,
+    pub origin: Vec2,
+    pub max_distance: f32,
+    pub frame: usize,
+    pub frame_timer: Timer,
+}
+
+/// Marks an enemy winding up a ranged attack, its sprite pulses until the projectile is out.
+#[derive(Component, Debug, Default)]
+pub struct AttackTelegraph {
+    pub seconds: f32,
+}
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+use systems::*;
+
+use crate::AppState;
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+/// Enemies that telegraph their attacks and fire projectiles the player can dodge.
+pub struct RangedPlugin;
+
+impl Plugin for RangedPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, load_enemy_projectile_assets)
+            .add_systems(
+                Update,
+                (
+                    attack_telegraph_system,
+                    fire_enemy_projectile_system,
+                    enemy_projectile_flight_system,
+                    enemy_projectile_collision_system,
+                )
+                    .run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(OnExit(AppState::InGame), despawn_enemy_projectiles_system);
+    }
+}
@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+#[derive(Resource, Default)]
+pub struct EnemyProjectileAssets {
+    /// Vomit glob animation while the projectile is flying.
+    pub flight_frames: Vec<Handle<Image>>,
+    /// Splash played where the projectile hits something or runs out of range.
+    pub splash_frames: Vec<Handle<Image>>,
+}
@@ -0,0 +1,257 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+use crate::{
+    collision::GameLayer,
+    enemy::ai::{
+        components::{AiState, EnemyAi},
+        events::EnemyAttackEvent,
+    },
+    hit_feedback::{components::HitFlash, events::HitEvent},
+    player::{
+        character::components::PassiveAbility,
+        components::{DamageInvulnerability, Player, PlayerHealth},
+        weapon::pool::{play_effect, EffectPool},
+    },
+    sprites::frame_path,
+    status_effects::events::ApplyStatusEffectEvent,
+};
+
+use super::{
+    components::{AttackTelegraph, EnemyProjectile, RangedAttack},
+    resources::EnemyProjectileAssets,
+};
+
+const VOMIT_FOLDER: &str = "Turret Zombie Vomit Shooting Animation Frames";
+const FLIGHT_FRAMES: std::ops::RangeInclusive<u32> = 469..=472;
+const SPLASH_FRAMES: std::ops::RangeInclusive<u32> = 473..=475;
+const FLIGHT_FRAME_SECONDS: f32 = 0.08;
+const SPLASH_FRAME_SECONDS: f32 = 0.06;
+const PROJECTILE_SCALE: f32 = 2.0;
+const PROJECTILE_Z: f32 = 8.0;
+const TELEGRAPH_COLOR: Color = Color::srgb(0.55, 1.0, 0.35);
+/// Pulses per second of a winding up enemy's tint.
+const TELEGRAPH_PULSE: f32 = 4.0;
+
+pub fn load_enemy_projectile_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(EnemyProjectileAssets {
+        flight_frames: FLIGHT_FRAMES
+            .map(|frame| asset_server.load(frame_path(VOMIT_FOLDER, frame)))
+            .collect(),
+        splash_frames: SPLASH_FRAMES
+            .map(|frame| asset_server.load(frame_path(VOMIT_FOLDER, frame)))
+            .collect(),
+    });
+}
+
+/// Tints ranged enemies while they wind up an attack so the player sees the shot coming.
+pub fn attack_telegraph_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut enemy_query: Query<
+        (
+            Entity,
+            &EnemyAi,
+            &mut Sprite,
+            Option<&mut AttackTelegraph>,
+            Has<HitFlash>,
+        ),
+        With<RangedAttack>,
+    >,
+) {
+    for (entity, ai, mut sprite, telegraph, flashing) in enemy_query.iter_mut() {
+        let winding_up = matches!(ai.state, AiState::AttackWindup { .. });
+        match (winding_up, telegraph) {
+            (true, None) => {
+                commands.entity(entity).insert(AttackTelegraph::default());
+            }
+            (true, Some(mut telegraph)) => {
+                telegraph.seconds += time.delta_secs();
+                // The hit flash owns the sprite color while it lasts.
+                if !flashing {
+                    let pulse = (telegraph.seconds * TELEGRAPH_PULSE * std::f32::consts::TAU).sin()
+                        * 0.5
+                        + 0.5;
+                    sprite.color = Color::WHITE.mix(&TELEGRAPH_COLOR, pulse);
+                }
+            }
+            (false, Some(_)) => {
+                commands.entity(entity).remove::<AttackTelegraph>();
+                if !flashing {
+                    sprite.color = Color::WHITE;
+                }
+            }
+            (false, None) => {}
+        }
+    }
+}
+
+/// Fires a projectile for every attack released by a ranged enemy.
+pub fn fire_enemy_projectile_system(
+    mut commands: Commands,
+    assets: Res<EnemyProjectileAssets>,
+    enemy_query: Query<(&Transform, &RangedAttack)>,
+    mut attack_events: EventReader<EnemyAttackEvent>,
+) {
+    for event in attack_events.read() {
+        let Ok((transform, ranged_attack)) = enemy_query.get(event.enemy) else {
+            continue;
+        };
+        if event.direction == Vec2::ZERO {
+            continue;
+        }
+        let origin = transform.translation.truncate();
+
+        commands.spawn((
+            EnemyProjectile {
+                damage: ranged_attack.damage,
+                status_effect: ranged_attack.status_effect,
+                origin,
+                max_distance: ranged_attack.max_distance,
+                frame: 0,
+                frame_timer: Timer::from_seconds(FLIGHT_FRAME_SECONDS, TimerMode::Repeating),
+            },
+            Sprite {
+                image: assets.flight_frames.first().cloned().unwrap_or_default(),
+                ..default()
+            },
+            Transform::from_translation(origin.extend(PROJECTILE_Z))
+                .with_rotation(Quat::from_rotation_z(event.direction.to_angle()))
+                .with_scale(Vec3::splat(PROJECTILE_SCALE)),
+            RigidBody::Dynamic,
+            Collider::circle(ranged_attack.projectile_radius / PROJECTILE_SCALE),
+            // Enemy projectiles pass through what they hit and resolve hits themselves.
+            Sensor,
+            LockedAxes::ROTATION_LOCKED,
+            LinearVelocity(event.direction * ranged_attack.projectile_speed),
+            CollisionLayers::new(
+                GameLayer::ENEMY_PROJECTILE,
+                [GameLayer::PLAYER, GameLayer::TERRAIN],
+            ),
+            SweptCcd::default(),
+        ));
+    }
+}
+
+/// Animates enemy projectiles and splashes them once they are out of range.
+pub fn enemy_projectile_flight_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    assets: Res<EnemyProjectileAssets>,
+    mut effect_pool: ResMut<EffectPool>,
+    mut projectile_query: Query<(Entity, &mut EnemyProjectile, &mut Sprite, &Transform)>,
+) {
+    for (entity, mut projectile, mut sprite, transform) in projectile_query.iter_mut() {
+        if projectile.frame_timer.tick(time.delta()).just_finished()
+            && !assets.flight_frames.is_empty()
+        {
+            projectile.frame = (projectile.frame + 1) % assets.flight_frames.len();
+            sprite.image = assets.flight_frames[projectile.frame].clone();
+        }
+
+        let position = transform.translation.truncate();
+        if position.distance(projectile.origin) >= projectile.max_distance {
+            splash(&mut commands, &mut effect_pool, &assets, position);
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Damages the player through the same invulnerability window as enemy contact, and splashes
+/// projectiles on terrain. Projectiles fly through a player who is still invulnerable.
+#[allow(clippy::too_many_arguments)]
+pub fn enemy_projectile_collision_system(
+    mut commands: Commands,
+    assets: Res<EnemyProjectileAssets>,
+    mut effect_pool: ResMut<EffectPool>,
+    projectile_query: Query<(&EnemyProjectile, &Transform, &LinearVelocity)>,
+    mut player_query: Query<
+        (
+            &mut PlayerHealth,
+            Option<&DamageInvulnerability>,
+            Option<&PassiveAbility>,
+        ),
+        With<Player>,
+    >,
+    mut collision_events: EventReader<Collision>,
+    mut hit_events: EventWriter<HitEvent>,
+    mut status_effect_events: EventWriter<ApplyStatusEffectEvent>,
+) {
+    let mut spent_projectiles = Vec::new();
+    let mut player_hit = false;
+
+    for collision in collision_events.read() {
+        for (projectile_entity, other_entity) in [
+            (collision.0.entity1, collision.0.entity2),
+            (collision.0.entity2, collision.0.entity1),
+        ] {
+            if spent_projectiles.contains(&projectile_entity) {
+                continue;
+            }
+            let Ok((projectile, transform, velocity)) = projectile_query.get(projectile_entity)
+            else {
+                continue;
+            };
+
+            if let Ok((mut player_health, invulnerability, passive)) =
+                player_query.get_mut(other_entity)
+            {
+                let invulnerable = invulnerability
+                    .is_some_and(|invulnerability| !invulnerability.timer.finished());
+                if player_hit || invulnerable {
+                    continue;
+                }
+                player_hit = true;
+                player_health.decrease_health(projectile.damage);
+                hit_events.send(HitEvent {
+                    target: other_entity,
+                    direction: velocity.0,
+                    force: 1.0,
+                });
+                commands
+                    .entity(other_entity)
+                    .insert(PassiveAbility::damage_invulnerability(passive));
+                if let Some(effect) = projectile.status_effect {
+                    status_effect_events.send(ApplyStatusEffectEvent {
+                        target: other_entity,
+                        effect,
+                    });
+                }
+            }
+
+            spent_projectiles.push(projectile_entity);
+            splash(
+                &mut commands,
+                &mut effect_pool,
+                &assets,
+                transform.translation.truncate(),
+            );
+            commands.entity(projectile_entity).despawn();
+        }
+    }
+}
+
+pub fn despawn_enemy_projectiles_system(
+    mut commands: Commands,
+    projectile_query: Query<Entity, With<EnemyProjectile>>,
+) {
+    for entity in projectile_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn splash(
+    commands: &mut Commands,
+    effect_pool: &mut EffectPool,
+    assets: &EnemyProjectileAssets,
+    position: Vec2,
+) {
+    play_effect(
+        commands,
+        effect_pool,
+        &assets.splash_frames,
+        SPLASH_FRAME_SECONDS,
+        Transform::from_translation(position.extend(PROJECTILE_Z))
+            .with_scale(Vec3::splat(PROJECTILE_SCALE)),
+    );
+}
@@ -1,4 +1,172 @@
-use bevy::prelude::Resource;
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::{hit_feedback::components::HitReaction, sprites::frame_path};
+
+use super::{
+    ai::components::AiProfile, components::FacingDirection, death::components::Remains,
+    ranged::components::RangedAttack,
+};
 
 #[derive(Resource)]
 pub struct EnemyKillCount(pub u32);
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EnemyAnimationSet {
+    pub folder: String,
+    pub down: u32,
+    pub side: u32,
+    pub up: u32,
+    pub side_faces: FacingDirection,
+}
+
+impl EnemyAnimationSet {
+    pub fn first_frame(&self, direction: FacingDirection) -> u32 {
+        match direction {
+            FacingDirection::Down => self.down,
+            FacingDirection::Up => self.up,
+            FacingDirection::Left | FacingDirection::Right => self.side,
+        }
+    }
+
+    pub fn frame_path(&self, frame: u32) -> String {
+        frame_path(&self.folder, frame)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct HitReactionSettings {
+    pub knockback_strength: f32,
+    pub knockback_duration: f32,
+    pub flash_duration: f32,
+    pub flash_color: (f32, f32, f32),
+    pub hit_stop: f32,
+}
+
+impl HitReactionSettings {
+    pub fn hit_reaction(&self) -> HitReaction {
+        let (red, green, blue) = self.flash_color;
+        HitReaction {
+            knockback_strength: self.knockback_strength,
+            knockback_duration: self.knockback_duration,
+            flash_duration: self.flash_duration,
+            flash_color: Color::srgb(red, green, blue),
+            hit_stop: self.hit_stop,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EnemyArchetype {
+    pub id: String,
+    /// Points this enemy takes from a wave's budget.
+    #[serde(default = "default_cost")]
+    pub cost: u32,
+    pub health: f32,
+    pub speed: f32,
+    /// Hearts the player loses when touched by this enemy.
+    pub contact_damage: u8,
+    pub sprite_size: f32,
+    pub hitbox: (f32, f32),
+    pub mass: f32,
+    pub animation: EnemyAnimationSet,
+    /// Frames shown while the enemy flashes after being hit.
+    #[serde(default)]
+    pub damaged_animation: Option<EnemyAnimationSet>,
+    pub hit_reaction: HitReactionSettings,
+    pub ai: AiProfile,
+    /// Projectile fired whenever the enemy attacks, enemies without one attack in melee.
+    #[serde(default)]
+    pub ranged_attack: Option<RangedAttack>,
+    /// Id of the loot table rolled when the enemy dies.
+    #[serde(default)]
+    pub loot_table: Option<String>,
+    /// What the enemy leaves on the ground when it dies.
+    #[serde(default)]
+    pub remains: Remains,
+}
+
+fn default_cost() -> u32 {
+    1
+}
+
+/// Every enemy archetype, loaded from `assets/data/enemies.ron`.
+#[derive(Resource, Debug, Deserialize)]
+pub struct EnemyRegistry {
+    pub enemies: Vec<EnemyArchetype>,
+}
+
+impl EnemyRegistry {
+    pub fn load() -> Self {
+        ron::from_str(include_str!("../../assets/data/enemies.ron"))
+            .expect("assets/data/enemies.ron should contain valid enemy archetypes")
+    }
+
+    pub fn get(&self, id: &str) -> Option<&EnemyArchetype> {
+        self.enemies.iter().find(|enemy| enemy.id == id)
+    }
+}
+
+pub struct ArchetypeAnimationFrames {
+    pub frames: HashMap<FacingDirection, Vec<Handle<Image>>>,
+    /// Empty if the archetype has no damaged animation.
+    pub damaged_frames: HashMap<FacingDirection, Vec<Handle<Image>>>,
+    pub flipped_direction: FacingDirection,
+}
+
+/// Walking animation frames per enemy archetype id.
+#[derive(Resource)]
+pub struct EnemyAnimationFrames(pub HashMap<String, ArchetypeAnimationFrames>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enemy_registry_loads() {
+        let registry = EnemyRegistry::load();
+        assert!(!registry.enemies.is_empty());
+        for (index, enemy) in registry.enemies.iter().enumerate() {
+            assert!(
+                registry.enemies[..index]
+                    .iter()
+                    .all(|other| other.id != enemy.id),
+                "{} is defined twice",
+                enemy.id
+            );
+            assert!(enemy.health > 0.0, "{} starts dead", enemy.id);
+            assert!(enemy.hitbox.0 > 0.0 && enemy.hitbox.1 > 0.0);
+        }
+        assert!(registry.get("skinny_zombie").is_some());
+        assert!(registry.get("missing").is_none());
+    }
+
+    #[test]
+    fn test_ranged_enemies_can_attack() {
+        let registry = EnemyRegistry::load();
+        let turret = registry.get("turret_zombie").unwrap();
+        let ranged_attack = turret.ranged_attack.unwrap();
+        let attack = turret.ai.attack.unwrap();
+        assert!(ranged_attack.max_distance >= attack.range);
+        assert!(turret.ai.preferred_distance.unwrap() < attack.range);
+        assert!(registry
+            .get("skinny_zombie")
+            .unwrap()
+            .ranged_attack
+            .is_none());
+    }
+
+    #[test]
+    fn test_side_frames_are_used_for_both_sides() {
+        let registry = EnemyRegistry::load();
+        let animation = &registry.get("kid_zombie").unwrap().animation;
+        assert_eq!(
+            animation.first_frame(FacingDirection::Left),
+            animation.first_frame(FacingDirection::Right)
+        );
+        assert!(animation
+            .frame_path(animation.down)
+            .ends_with("Kid Zombie Animation Frames/Zombie-Tileset---_0430_Capa-431.png"));
+    }
+}
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use resources::SpatialHash;
+use systems::*;
+
+use crate::AppState;
+
+pub mod resources;
+pub mod systems;
+
+/// Keeps the enemy spatial hash up to date for neighbour queries.
+pub struct SpatialHashPlugin;
+
+impl Plugin for SpatialHashPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpatialHash>().add_systems(
+            PreUpdate,
+            rebuild_spatial_hash_system.run_if(in_state(AppState::InGame)),
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+/// Side length of a cell in world units, a bit larger than an enemy so most neighbour queries
+/// only look at a handful of cells.
+pub const CELL_SIZE: f32 = 64.0;
+
+/// Uniform grid of every enemy's position, rebuilt at the start of each frame. Anything that
+/// needs the enemies near a point queries it instead of comparing against every enemy.
+#[derive(Resource, Debug)]
+pub struct SpatialHash {
+    cell_size: f32,
+    cells: HashMap<(i32, i32), Vec<(Entity, Vec2)>>,
+}
+
+impl Default for SpatialHash {
+    fn default() -> Self {
+        Self::new(CELL_SIZE)
+    }
+}
+
+impl SpatialHash {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            cells: HashMap::default(),
+        }
+    }
+
+    /// Removes every entry. Cells that were occupied keep their allocations for the next
+    /// rebuild, cells that stayed empty since the last clear are dropped so the map does not
+    /// grow with every cell an enemy ever passed through.
+    pub fn clear(&mut self) {
+        self.cells.retain(|_, entries| {
+            let occupied = !entries.is_empty();
+            entries.clear();
+            occupied
+        });
+    }
+
+    pub fn insert(&mut self, entity: Entity, position: Vec2) {
+        self.cells
+            .entry(self.cell(position))
+            .or_default()
+            .push((entity, position));
+    }
+
+    /// Entities within `radius` of `center`, with their positions.
+    pub fn within(&self, center: Vec2, radius: f32) -> impl Iterator<Item = (Entity, Vec2)> + '_ {
+        let (min_x, min_y) = self.cell(center - Vec2::splat(radius));
+        let (max_x, max_y) = self.cell(center + Vec2::splat(radius));
+        let radius_squared = radius * radius;
+        (min_x..=max_x)
+            .flat_map(move |x| (min_y..=max_y).map(move |y| (x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+            .filter(move |(_, position)| position.distance_squared(center) <= radius_squared)
+    }
+
+    fn cell(&self, position: Vec2) -> (i32, i32) {
+        (
+            (position.x / self.cell_size).floor() as i32,
+            (position.y / self.cell_size).floor() as i32,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_within_matches_brute_force() {
+        let mut hash = SpatialHash::new(10.0);
+        let positions: Vec<(Entity, Vec2)> = (0..200)
+            .map(|index| {
+                let angle = index as f32 * 2.4;
+                let position = Vec2::from_angle(angle) * (index as f32 * 0.7) - Vec2::splat(15.0);
+                (Entity::from_raw(index), position)
+            })
+            .collect();
+        for (entity, position) in positions.iter() {
+            hash.insert(*entity, *position);
+        }
+        assert_eq!(hash.within(Vec2::ZERO, 1000.0).count(), positions.len());
+
+        for (center, radius) in [(Vec2::ZERO, 25.0), (Vec2::new(-40.0, 33.0), 12.5)] {
+            let mut found: Vec<Entity> = hash
+                .within(center, radius)
+                .map(|(entity, _)| entity)
+                .collect();
+            let mut expected: Vec<Entity> = positions
+                .iter()
+                .filter(|(_, position)| position.distance(center) <= radius)
+                .map(|(entity, _)| *entity)
+                .collect();
+            found.sort();
+            expected.sort();
+            assert_eq!(found, expected);
+        }
+    }
+
+    #[test]
+    fn test_clear_empties_the_hash() {
+        let mut hash = SpatialHash::default();
+        hash.insert(Entity::from_raw(1), Vec2::new(100.0, -100.0));
+        assert_eq!(hash.within(Vec2::new(100.0, -100.0), 10.0).count(), 1);
+        hash.clear();
+        assert_eq!(hash.within(Vec2::new(100.0, -100.0), 10.0).count(), 0);
+        hash.clear();
+        assert!(hash.cells.is_empty());
+    }
+}
@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+use crate::enemy::components::Enemy;
+
+use super::resources::SpatialHash;
+
+pub fn rebuild_spatial_hash_system(
+    mut spatial_hash: ResMut<SpatialHash>,
+    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+) {
+    spatial_hash.clear();
+    for (entity, transform) in enemy_query.iter() {
+        spatial_hash.insert(entity, transform.translation.truncate());
+    }
+}
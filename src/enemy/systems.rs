@@ -1,109 +1,75 @@
+use super::ai::components::{AiProfile, AiState, Awareness, EnemyAi};
 use super::components::*;
-use super::resources::EnemyKillCount;
+use super::events::EnemyKilledEvent;
+use super::resources::{
+    ArchetypeAnimationFrames, EnemyAnimationFrames, EnemyAnimationSet, EnemyArchetype,
+    EnemyKillCount, EnemyRegistry,
+};
+use super::spatial::resources::SpatialHash;
 use crate::collision::GameLayer;
-use crate::player::components::Player;
+use crate::hit_feedback::components::{HitFlash, Knockback};
+use crate::hit_feedback::events::DamageEvent;
+use crate::navigation::resources::FlowField;
+use crate::status_effects::components::StatusEffects;
 use avian2d::collision::Collider;
 use avian2d::prelude::*;
 use bevy::prelude::*;
-use rand::prelude::*;
 use std::collections::HashMap;
-use std::f32::consts::PI;
 
-pub fn spawn_enemy_system(
-    mut commands: Commands,
-    time: Res<Time>,
-    mut spawn_timer: ResMut<SpawnTimer>,
-    mut wave_timer: ResMut<WaveTimer>,
-    player_query: Query<&Transform, With<Player>>,
-    asset_server: Res<AssetServer>,
-) {
-    spawn_timer.0.tick(time.delta());
-    wave_timer.timer.tick(time.delta());
-
-    if wave_timer.timer.just_finished() {
-        wave_timer.wave += 1;
-    }
-
-    if spawn_timer.0.just_finished() {
-        let player_transform = player_query.single();
-        let spawn_distance = 500.0;
-        let mut rng = thread_rng();
-        let angle = rng.gen_range(0.0..2.0 * PI);
-        let spawn_position = Vec3::new(
-            player_transform.translation.x + spawn_distance * angle.cos(),
-            player_transform.translation.y + spawn_distance * angle.sin(),
-            10.0,
-        );
-
-        let enemy_health: f32;
-        let enemy_type = match wave_timer.wave {
-            0..=2 => {
-                enemy_health = 100.0;
-                EnemyType::Zombie { speed: 50.0 }
-            }
-            3..=5 => {
-                enemy_health = 75.0;
-                EnemyType::Skeleton { speed: 75.0 }
-            }
-            _ => {
-                enemy_health = 200.0;
-                EnemyType::Boss { speed: 40.0 }
-            }
-        };
-
-        let sprite_path = match enemy_type {
-            EnemyType::Boss { .. } => "zombie_apocalypse_tileset/organized_separated_sprites/Kid Zombie Animation Frames/Zombie-Tileset---_0430_Capa-431.png",
-            EnemyType::Skeleton { .. } => "zombie_apocalypse_tileset/organized_separated_sprites/Kid Zombie Animation Frames/Zombie-Tileset---_0430_Capa-431.png",
-            EnemyType::Zombie { .. } => "zombie_apocalypse_tileset/organized_separated_sprites/Kid Zombie Animation Frames/Zombie-Tileset---_0430_Capa-431.png",
-        };
-
-        let enemy_size = match enemy_type {
-            EnemyType::Zombie { .. } => Vec2::new(32.0, 32.0),
-            EnemyType::Skeleton { .. } => Vec2::new(32.0, 32.0),
-            EnemyType::Boss { .. } => Vec2::new(64.0, 64.0),
-        };
-
-        let hitbox_size = match enemy_type {
-            EnemyType::Zombie { .. } => Vec2::new(16.0, 20.0),
-            EnemyType::Skeleton { .. } => Vec2::new(16.0, 20.0),
-            EnemyType::Boss { .. } => Vec2::new(32.0, 40.0),
-        };
-
-        let texture_handle = asset_server.load(sprite_path);
-
-        commands.spawn((
+/// Distance below which enemies push each other apart.
+const SEPARATION_DISTANCE: f32 = 24.0;
+/// Speed at which two enemies on the same spot move apart.
+const SEPARATION_SPEED: f32 = 80.0;
+
+/// Spawns an enemy of the given archetype, the archetype decides its stats, looks and hitbox.
+pub fn spawn_enemy(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    archetype: &EnemyArchetype,
+    position: Vec3,
+) -> Entity {
+    let (hitbox_width, hitbox_height) = archetype.hitbox;
+
+    let mut enemy = commands.spawn((
+        (
             Enemy,
-            enemy_type,
-            EnemyHealth(enemy_health),
-            FacingDirection::Down,
-            Transform {
-                translation: spawn_position,
-                rotation: Quat::IDENTITY,
-                scale: Vec3::ONE,
-            },
-            Sprite {
-                custom_size: Some(Vec2::new(enemy_size.x, enemy_size.y)),
-                image: texture_handle,
-                ..Default::default()
-            },
-            RigidBody::Dynamic,
-            Collider::round_rectangle(hitbox_size.x, hitbox_size.y, 5.0),
-            Mass(5.0),
-            AnimationTimer(Timer::from_seconds(0.2, TimerMode::Repeating)),
-            AnimationFrame(0),
-            LinearVelocity(Vec2::ZERO),
-            LockedAxes::ROTATION_LOCKED,
-            CollisionLayers::new(
+            EnemyType(archetype.id.clone()),
+            EnemyHealth(archetype.health),
+            EnemyMovementSpeed(archetype.speed),
+            ContactDamage(archetype.contact_damage),
+            archetype.ai,
+            archetype.hit_reaction.hit_reaction(),
+        ),
+        FacingDirection::Down,
+        Transform::from_translation(position),
+        Sprite {
+            custom_size: Some(Vec2::splat(archetype.sprite_size)),
+            image: asset_server.load(archetype.animation.frame_path(archetype.animation.down)),
+            ..Default::default()
+        },
+        RigidBody::Dynamic,
+        Collider::round_rectangle(hitbox_width, hitbox_height, 5.0),
+        Mass(archetype.mass),
+        AnimationTimer(Timer::from_seconds(0.2, TimerMode::Repeating)),
+        AnimationFrame(0),
+        LinearVelocity(Vec2::ZERO),
+        LockedAxes::ROTATION_LOCKED,
+        CollisionLayers::new(
+            GameLayer::ENEMY,
+            [
+                GameLayer::ENEMY,
+                GameLayer::PROJECTILE,
+                GameLayer::PLAYER,
                 GameLayer::ENEMY,
-                [
-                    GameLayer::ENEMY,
-                    GameLayer::PROJECTILE,
-                    GameLayer::PLAYER,
-                    GameLayer::ENEMY,
-                ],
-            ),
-        ));
+                GameLayer::MELEE,
+                GameLayer::TERRAIN,
+            ],
+        ),
+    ));
+    if let Some(ranged_attack) = archetype.ranged_attack {
+        enemy.insert(ranged_attack);
     }
+    enemy.id()
 }
 
 pub fn enemy_movement_and_direction_system(
@@ -113,34 +79,68 @@ pub fn enemy_movement_and_direction_system(
         &mut FacingDirection,
         &mut Sprite,
         &EnemyType,
+        &EnemyMovementSpeed,
+        &AiProfile,
+        &EnemyAi,
+        &Awareness,
         &AnimationFrame,
+        Option<&Knockback>,
+        Option<&HitFlash>,
+        &StatusEffects,
     )>,
-    player_query: Query<&Transform, With<Player>>,
     animation_frames: Res<EnemyAnimationFrames>,
+    flow_field: Res<FlowField>,
 ) {
-    let Ok(player_transform) = player_query.get_single() else {
-        return;
-    };
-    let player_pos_2d = player_transform.translation.truncate();
-
-    for (enemy_transform, mut velocity, mut facing, mut sprite, enemy_type, anim_frame) in
-        enemy_query.iter_mut()
+    for (
+        enemy_transform,
+        mut velocity,
+        mut facing,
+        mut sprite,
+        enemy_type,
+        movement_speed,
+        ai_profile,
+        ai,
+        awareness,
+        anim_frame,
+        knockback,
+        hit_flash,
+        status_effects,
+    ) in enemy_query.iter_mut()
     {
         let enemy_pos_2d = enemy_transform.translation.truncate();
 
-        let direction = (player_pos_2d - enemy_pos_2d).normalize_or_zero();
-
-        let speed = match enemy_type {
-            EnemyType::Zombie { speed, .. } => *speed,
-            EnemyType::Skeleton { speed, .. } => *speed,
-            EnemyType::Boss { speed, .. } => *speed,
+        let mut steering = ai.steering(ai_profile, awareness, enemy_pos_2d);
+        // Enemies closing in on the player follow the flow field around water and obstacles,
+        // close to the player or off the field they head straight for them.
+        let closing_in = awareness
+            .last_seen
+            .is_some_and(|last_seen| steering.dot(last_seen - enemy_pos_2d) > 0.0);
+        if ai.state == AiState::Chase && closing_in {
+            if let Some(path_direction) = flow_field.direction(enemy_pos_2d) {
+                steering = path_direction * steering.length();
+            }
+        }
+        // Enemies standing still keep looking at where they last saw the player.
+        let direction = if steering != Vec2::ZERO {
+            steering.normalize_or_zero()
+        } else {
+            awareness.last_seen.map_or(Vec2::ZERO, |last_seen| {
+                (last_seen - enemy_pos_2d).normalize_or_zero()
+            })
         };
 
-        let target_velocity = direction * speed;
+        let speed = movement_speed.0 * status_effects.speed_multiplier();
+
+        let target_velocity = match knockback {
+            Some(knockback) => knockback.current_velocity(),
+            None => steering * speed,
+        };
         velocity.x = target_velocity.x;
         velocity.y = target_velocity.y;
 
-        let new_direction = if direction.x.abs() > direction.y.abs() {
+        let new_direction = if direction == Vec2::ZERO {
+            *facing
+        } else if direction.x.abs() > direction.y.abs() {
             // Horizontal movement is stroner
             if direction.x > 0.0 {
                 FacingDirection::Right
@@ -160,10 +160,14 @@ pub fn enemy_movement_and_direction_system(
             *facing = new_direction;
         }
 
-        let frames_map = match enemy_type {
-            EnemyType::Zombie { .. } => &animation_frames.zombie,
-            EnemyType::Skeleton { .. } => &animation_frames.skeleton,
-            EnemyType::Boss { .. } => &animation_frames.boss,
+        let Some(archetype_frames) = animation_frames.0.get(&enemy_type.0) else {
+            warn_once!("No animation frames found for enemy {}!", enemy_type.0);
+            continue;
+        };
+        let frames_map = if hit_flash.is_some() && !archetype_frames.damaged_frames.is_empty() {
+            &archetype_frames.damaged_frames
+        } else {
+            &archetype_frames.frames
         };
 
         if let Some(direction_frames) = frames_map.get(&new_direction) {
@@ -177,7 +181,7 @@ pub fn enemy_movement_and_direction_system(
 
             if frame_index < direction_frames.len() {
                 sprite.image = direction_frames[frame_index].clone();
-                sprite.flip_x = new_direction == FacingDirection::Right;
+                sprite.flip_x = new_direction == archetype_frames.flipped_direction;
             } else {
                 warn_once!(
                     "Animation frame index {} out of bounds for direction {:?}!",
@@ -194,48 +198,26 @@ pub fn enemy_movement_and_direction_system(
     }
 }
 
-pub fn wave_timer_system(mut wave_timer: ResMut<WaveTimer>, time: Res<Time>) {
-    if wave_timer.timer.tick(time.delta()).just_finished() {
-        wave_timer.wave += 1;
-        info!("Wave {} started!", wave_timer.wave);
-    }
-}
-
-pub fn prevent_enemy_overlap_system(
-    mut query: Query<(&mut Transform, &LinearVelocity, Entity), With<Enemy>>,
+/// Pushes overlapping enemies apart through their velocity so the physics engine resolves the
+/// movement. Runs after the movement system, which sets each enemy's velocity from scratch.
+pub fn enemy_separation_system(
+    spatial_hash: Res<SpatialHash>,
+    mut query: Query<(Entity, &Transform, &mut LinearVelocity), With<Enemy>>,
 ) {
-    let enemies: Vec<(Entity, Vec3, Vec2)> = query
-        .iter()
-        .map(|(transform, velocity, entity)| (entity, transform.translation, velocity.0))
-        .collect();
-
-    for i in 0..enemies.len() {
-        for j in (i + 1)..enemies.len() {
-            let (entity_a, pos_a, vel_a) = enemies[i];
-            let (entity_b, pos_b, vel_b) = enemies[j];
-
-            let distance = pos_a.distance(pos_b);
-            let min_distance = 24.0;
-
-            if distance < min_distance {
-                let direction = (pos_b - pos_a).normalize_or_zero();
-                let overlap = min_distance - distance;
-
-                let relative_velocity = vel_b - vel_a;
-                let approaching = relative_velocity.dot(direction.truncate()) < 0.0;
-
-                if approaching {
-                    let separation = direction * (overlap * 0.5);
-
-                    if let Ok((mut transform_a, _, _)) = query.get_mut(entity_a) {
-                        transform_a.translation -= separation * 0.7;
-                    }
-                    if let Ok((mut transform_b, _, _)) = query.get_mut(entity_b) {
-                        transform_b.translation += separation * 0.7;
-                    }
-                }
-            }
-        }
+    for (entity, transform, mut velocity) in query.iter_mut() {
+        let position = transform.translation.truncate();
+        let push: Vec2 = spatial_hash
+            .within(position, SEPARATION_DISTANCE)
+            .filter(|(other, _)| *other != entity)
+            .map(|(other, other_position)| {
+                let offset = position - other_position;
+                // Enemies on the exact same spot still need to go separate ways.
+                let fallback = if entity < other { Vec2::X } else { -Vec2::X };
+                let overlap = 1.0 - offset.length() / SEPARATION_DISTANCE;
+                offset.normalize_or(fallback) * overlap
+            })
+            .sum();
+        velocity.0 += push * SEPARATION_SPEED;
     }
 }
 
@@ -257,83 +239,105 @@ pub fn animate_enemy_system(
     }
 }
 
-pub fn setup_enemy_sprites(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let mut zombie_frames: HashMap<FacingDirection, Vec<Handle<Image>>> = HashMap::new();
-    let mut skeleton_frames: HashMap<FacingDirection, Vec<Handle<Image>>> = HashMap::new();
-    let mut boss_frames: HashMap<FacingDirection, Vec<Handle<Image>>> = HashMap::new();
+fn load_enemy_frames(
+    asset_server: &AssetServer,
+    animation: &EnemyAnimationSet,
+    flipped_direction: FacingDirection,
+) -> HashMap<FacingDirection, Vec<Handle<Image>>> {
+    let mut enemy_frames: HashMap<FacingDirection, Vec<Handle<Image>>> = HashMap::new();
 
     for direction in [
         FacingDirection::Down,
         FacingDirection::Up,
-        FacingDirection::Left,
+        animation.side_faces,
     ] {
-        let (zombie_base, skeleton_base, boss_base) = match direction {
-            FacingDirection::Down => ("394", "430", "412"),
-            FacingDirection::Up => ("400", "436", "418"),
-            FacingDirection::Left => ("397", "433", "415"),
-            _ => continue,
-        };
-
-        let enemy_types = [
-            (
-                "Skinny Walking Zombie Animation",
-                zombie_base,
-                &mut zombie_frames,
-            ),
-            (
-                "Kid Zombie Animation Frames",
-                skeleton_base,
-                &mut skeleton_frames,
-            ),
-            (
-                "Big Zombie Walking Animation Frames",
-                boss_base,
-                &mut boss_frames,
-            ),
-        ];
-
-        for (folder, base, frames) in enemy_types {
-            let frames_vec: Vec<Handle<Image>> = (0..3)
-                .map(|i| {
-                    let base = base.parse::<i32>().unwrap() + i;
-                    let next = base + 1;
-                    asset_server.load(format!(
-                        "zombie_apocalypse_tileset/organized_separated_sprites/{}/Zombie-Tileset---_0{}_Capa-{}.png",
-                        folder, base, next
-                    ))
-                })
-                .collect();
-            frames.insert(direction, frames_vec);
-        }
+        let frames_vec: Vec<Handle<Image>> = (0..3)
+            .map(|i| asset_server.load(animation.frame_path(animation.first_frame(direction) + i)))
+            .collect();
+        enemy_frames.insert(direction, frames_vec);
     }
 
-    if let Some(left_frames) = zombie_frames.get(&FacingDirection::Left) {
-        zombie_frames.insert(FacingDirection::Right, left_frames.clone());
+    if let Some(side_frames) = enemy_frames.get(&animation.side_faces) {
+        enemy_frames.insert(flipped_direction, side_frames.clone());
     }
-    if let Some(left_frames) = skeleton_frames.get(&FacingDirection::Left) {
-        skeleton_frames.insert(FacingDirection::Right, left_frames.clone());
-    }
-    if let Some(left_frames) = boss_frames.get(&FacingDirection::Left) {
-        boss_frames.insert(FacingDirection::Right, left_frames.clone());
+    enemy_frames
+}
+
+pub fn setup_enemy_sprites(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    registry: Res<EnemyRegistry>,
+) {
+    let mut archetype_frames: HashMap<String, ArchetypeAnimationFrames> = HashMap::new();
+
+    for archetype in registry.enemies.iter() {
+        let flipped_direction = match archetype.animation.side_faces {
+            FacingDirection::Left => FacingDirection::Right,
+            _ => FacingDirection::Left,
+        };
+        let frames = load_enemy_frames(&asset_server, &archetype.animation, flipped_direction);
+        let damaged_frames = archetype
+            .damaged_animation
+            .as_ref()
+            .map(|animation| load_enemy_frames(&asset_server, animation, flipped_direction))
+            .unwrap_or_default();
+
+        archetype_frames.insert(
+            archetype.id.clone(),
+            ArchetypeAnimationFrames {
+                frames,
+                damaged_frames,
+                flipped_direction,
+            },
+        );
     }
 
-    commands.insert_resource(EnemyAnimationFrames {
-        zombie: zombie_frames,
-        skeleton: skeleton_frames,
-        boss: boss_frames,
-    });
+    commands.insert_resource(EnemyAnimationFrames(archetype_frames));
+}
+
+pub fn reset_kill_count_system(mut kill_count: ResMut<EnemyKillCount>) {
+    kill_count.0 = 0;
 }
 
+/// Reports enemies without health as killed. The first lethal damage event of an enemy tells
+/// who and what killed it.
 pub fn kill_enemy_system(
-    mut commands: Commands,
     mut kill_count: ResMut<EnemyKillCount>,
-    mut enemy_query: Query<(&EnemyHealth, Entity)>,
+    enemy_query: Query<(&EnemyHealth, Entity, &EnemyType, &Transform)>,
+    mut damage_events: EventReader<DamageEvent>,
+    mut killed_events: EventWriter<EnemyKilledEvent>,
 ) {
-    for (enemy_health, enemy) in enemy_query.iter_mut() {
+    let lethal_hits: Vec<DamageEvent> = damage_events
+        .read()
+        .filter(|event| event.lethal)
+        .copied()
+        .collect();
+
+    for (enemy_health, enemy, enemy_type, transform) in enemy_query.iter() {
         if enemy_health.0 <= 0.0 {
             kill_count.0 += 1;
-            info!("Despawn Entity {:?}", enemy);
-            commands.entity(enemy).despawn();
+            let lethal_hit = lethal_hits.iter().find(|hit| hit.target == enemy);
+            killed_events.send(EnemyKilledEvent {
+                enemy,
+                killer: lethal_hit.and_then(|hit| hit.dealer),
+                position: transform.translation.truncate(),
+                archetype: enemy_type.0.clone(),
+                source: lethal_hit.map(|hit| hit.source),
+            });
+        }
+    }
+}
+
+/// Despawns killed enemies together with their children, like status indicators. Systems that
+/// need the enemy itself run between `kill_enemy_system` and this one.
+pub fn despawn_killed_enemies_system(
+    mut commands: Commands,
+    mut killed_events: EventReader<EnemyKilledEvent>,
+) {
+    for event in killed_events.read() {
+        info!("Despawn Entity {:?}", event.enemy);
+        if let Some(entity_commands) = commands.get_entity(event.enemy) {
+            entity_commands.despawn_recursive();
         }
     }
 }
@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+/// Number of the wave that spawned the enemy.
+#[derive(Component, Debug)]
+pub struct WaveMember(pub u32);
@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct WaveStartedEvent {
+    pub wave: u32,
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct WaveEndedEvent {
+    pub wave: u32,
+    /// Seconds until the next wave starts.
+    pub rest_seconds: f32,
+}
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+use events::{WaveEndedEvent, WaveStartedEvent};
+use resources::WaveSettings;
+use systems::*;
+
+use crate::AppState;
+
+pub mod components;
+pub mod events;
+pub mod resources;
+mod systems;
+
+/// Spawns enemies in waves defined in `assets/data/waves.ron`, with rests in between and a
+/// budget that grows with the run's length and the player's weapon.
+pub struct WavesPlugin;
+
+impl Plugin for WavesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<WaveStartedEvent>()
+            .add_event::<WaveEndedEvent>()
+            .insert_resource(WaveSettings::load())
+            .add_systems(OnEnter(AppState::InGame), reset_wave_director_system)
+            .add_systems(
+                Update,
+                wave_director_system.run_if(in_state(AppState::InGame)),
+            );
+    }
+}
@@ -0,0 +1,273 @@
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+use std::time::Duration;
+
+use crate::enemy::{
+    population::resources::PopulationSettings,
+    resources::{EnemyArchetype, EnemyRegistry},
+};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WaveDefinition {
+    pub budget: u32,
+    /// Archetype ids with their relative spawn weights.
+    pub composition: Vec<(String, f32)>,
+    /// Seconds between spawns at the start and at the end of the wave.
+    pub spawn_interval: (f32, f32),
+    /// Id of a boss in `assets/data/bosses.ron` that appears when the wave starts.
+    #[serde(default)]
+    pub boss: Option<String>,
+}
+
+impl WaveDefinition {
+    /// Seconds until the next spawn once `progress` (0 to 1) of the budget is spent.
+    pub fn spawn_interval_at(&self, progress: f32) -> f32 {
+        let (start, end) = self.spawn_interval;
+        start + (end - start) * progress.clamp(0.0, 1.0)
+    }
+
+    /// Picks a weighted archetype among the ones `budget_left` can still afford.
+    pub fn pick_archetype<'a>(
+        &self,
+        registry: &'a EnemyRegistry,
+        budget_left: u32,
+        rng: &mut impl Rng,
+    ) -> Option<&'a EnemyArchetype> {
+        let affordable: Vec<(&EnemyArchetype, f32)> = self
+            .composition
+            .iter()
+            .filter_map(|(id, weight)| registry.get(id).map(|archetype| (archetype, *weight)))
+            .filter(|(archetype, weight)| archetype.cost <= budget_left && *weight > 0.0)
+            .collect();
+        let total_weight: f32 = affordable.iter().map(|(_, weight)| weight).sum();
+        if total_weight <= 0.0 {
+            return None;
+        }
+
+        let mut roll = rng.gen_range(0.0..total_weight);
+        for (archetype, weight) in affordable.iter() {
+            if roll < *weight {
+                return Some(archetype);
+            }
+            roll -= weight;
+        }
+        affordable.last().map(|(archetype, _)| *archetype)
+    }
+}
+
+/// Waves generated once the scripted ones run out.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProceduralWaves {
+    /// Budget added on top of the previous wave's.
+    pub budget_growth: u32,
+    pub composition: Vec<(String, f32)>,
+    pub spawn_interval: (f32, f32),
+    #[serde(default)]
+    pub boss: Option<String>,
+    /// The boss appears in every `boss_every`th procedural wave, never when zero.
+    #[serde(default)]
+    pub boss_every: u32,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct DifficultyScaling {
+    pub budget_per_minute: f32,
+    pub health_per_minute: f32,
+    /// Damage per second of the weapon the budget is balanced around.
+    pub reference_dps: f32,
+    pub budget_per_power: f32,
+}
+
+impl DifficultyScaling {
+    pub fn budget_multiplier(&self, run_seconds: f32, player_dps: f32) -> f32 {
+        let extra_power = (player_dps / self.reference_dps.max(f32::EPSILON) - 1.0).max(0.0);
+        1.0 + run_seconds / 60.0 * self.budget_per_minute + extra_power * self.budget_per_power
+    }
+
+    pub fn health_multiplier(&self, run_seconds: f32) -> f32 {
+        1.0 + run_seconds / 60.0 * self.health_per_minute
+    }
+}
+
+#[derive(Resource, Debug, Deserialize)]
+pub struct WaveSettings {
+    pub first_wave_delay: f32,
+    pub rest_seconds: f32,
+    pub max_wave_seconds: f32,
+    pub waves: Vec<WaveDefinition>,
+    pub procedural: ProceduralWaves,
+    pub scaling: DifficultyScaling,
+    pub population: PopulationSettings,
+}
+
+impl WaveSettings {
+    pub fn load() -> Self {
+        ron::from_str(include_str!("../../../assets/data/waves.ron"))
+            .expect("assets/data/waves.ron should contain valid wave settings")
+    }
+
+    /// Definition of the wave with the given number, starting at 1.
+    pub fn wave(&self, number: u32) -> WaveDefinition {
+        let index = number.saturating_sub(1) as usize;
+        if let Some(wave) = self.waves.get(index) {
+            return wave.clone();
+        }
+
+        let last_budget = self.waves.last().map_or(0, |wave| wave.budget);
+        let procedural_index = (index + 1 - self.waves.len()) as u32;
+        let boss_every = self.procedural.boss_every;
+        WaveDefinition {
+            budget: last_budget + procedural_index * self.procedural.budget_growth,
+            composition: self.procedural.composition.clone(),
+            spawn_interval: self.procedural.spawn_interval,
+            boss: self
+                .procedural
+                .boss
+                .clone()
+                .filter(|_| boss_every > 0 && procedural_index.is_multiple_of(boss_every)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum WavePhase {
+    /// Waiting for the next wave to start.
+    Resting(Timer),
+    Active {
+        definition: WaveDefinition,
+        elapsed: f32,
+    },
+}
+
+/// Progress of the current run's waves.
+#[derive(Resource, Debug)]
+pub struct WaveDirector {
+    /// Number of the current or last wave, zero before the first one.
+    pub wave: u32,
+    pub phase: WavePhase,
+    /// Budget of the current wave after scaling.
+    pub budget: u32,
+    pub budget_left: u32,
+    pub spawn_timer: Timer,
+    pub run_seconds: f32,
+}
+
+impl WaveDirector {
+    pub fn new(settings: &WaveSettings) -> Self {
+        Self {
+            wave: 0,
+            phase: WavePhase::Resting(Timer::from_seconds(
+                settings.first_wave_delay,
+                TimerMode::Once,
+            )),
+            budget: 0,
+            budget_left: 0,
+            spawn_timer: Timer::default(),
+            run_seconds: 0.0,
+        }
+    }
+
+    pub fn start_wave(&mut self, settings: &WaveSettings, player_dps: f32) {
+        self.wave += 1;
+        let definition = settings.wave(self.wave);
+        let multiplier = settings
+            .scaling
+            .budget_multiplier(self.run_seconds, player_dps);
+        self.budget = ((definition.budget as f32 * multiplier).round() as u32).max(1);
+        self.budget_left = self.budget;
+        self.spawn_timer = Timer::from_seconds(definition.spawn_interval_at(0.0), TimerMode::Once);
+        self.phase = WavePhase::Active {
+            definition,
+            elapsed: 0.0,
+        };
+    }
+
+    pub fn end_wave(&mut self, settings: &WaveSettings) {
+        self.budget_left = 0;
+        self.phase =
+            WavePhase::Resting(Timer::from_seconds(settings.rest_seconds, TimerMode::Once));
+    }
+
+    /// Takes an enemy's cost from the budget and schedules the next spawn.
+    pub fn spend(&mut self, cost: u32, next_interval: f32) {
+        self.budget_left = self.budget_left.saturating_sub(cost);
+        self.spawn_timer
+            .set_duration(Duration::from_secs_f32(next_interval.max(0.0)));
+        self.spawn_timer.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_procedural_waves_follow_scripted_ones() {
+        let settings = WaveSettings::load();
+        let scripted = settings.waves.len() as u32;
+        let last = settings.wave(scripted);
+        let first_procedural = settings.wave(scripted + 1);
+        let second_procedural = settings.wave(scripted + 2);
+        assert_eq!(
+            first_procedural.budget,
+            last.budget + settings.procedural.budget_growth
+        );
+        assert_eq!(
+            second_procedural.budget,
+            first_procedural.budget + settings.procedural.budget_growth
+        );
+
+        let boss_every = settings.procedural.boss_every;
+        if boss_every > 0 {
+            assert!(settings.wave(scripted + boss_every).boss.is_some());
+            assert!(settings.wave(scripted + boss_every + 1).boss.is_none());
+        }
+    }
+
+    #[test]
+    fn test_compositions_only_use_known_archetypes() {
+        let settings = WaveSettings::load();
+        let registry = EnemyRegistry::load();
+        for wave in settings.waves.iter() {
+            for (id, _) in wave.composition.iter() {
+                assert!(registry.get(id).is_some(), "unknown archetype {}", id);
+            }
+        }
+        for (id, _) in settings.procedural.composition.iter() {
+            assert!(registry.get(id).is_some(), "unknown archetype {}", id);
+        }
+    }
+
+    #[test]
+    fn test_pick_archetype_respects_budget() {
+        let registry = EnemyRegistry::load();
+        let wave = WaveDefinition {
+            budget: 10,
+            composition: vec![("skinny_zombie".into(), 1.0), ("big_zombie".into(), 100.0)],
+            spawn_interval: (1.0, 1.0),
+            boss: None,
+        };
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..20 {
+            let picked = wave.pick_archetype(&registry, 1, &mut rng).unwrap();
+            assert_eq!(picked.id, "skinny_zombie");
+        }
+        assert!(wave.pick_archetype(&registry, 0, &mut rng).is_none());
+    }
+
+    #[test]
+    fn test_budget_scales_with_time_and_power() {
+        let scaling = DifficultyScaling {
+            budget_per_minute: 0.1,
+            health_per_minute: 0.05,
+            reference_dps: 80.0,
+            budget_per_power: 0.5,
+        };
+        assert_eq!(scaling.budget_multiplier(0.0, 40.0), 1.0);
+        assert!((scaling.budget_multiplier(120.0, 80.0) - 1.2).abs() < 1e-5);
+        assert!((scaling.budget_multiplier(0.0, 160.0) - 1.5).abs() < 1e-5);
+        assert!((scaling.health_multiplier(60.0) - 1.05).abs() < 1e-5);
+    }
+}
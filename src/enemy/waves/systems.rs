@@ -0,0 +1,128 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+
+use crate::{
+    enemy::{
+        population::{
+            resources::{Population, SpawnPoints, SpawnTelegraphAssets},
+            spawn_telegraph,
+        },
+        resources::EnemyRegistry,
+    },
+    player::{
+        components::Player,
+        weapon::{components::Weapon, resources::WeaponRegistry},
+    },
+};
+
+use super::{
+    components::WaveMember,
+    events::{WaveEndedEvent, WaveStartedEvent},
+    resources::{WaveDirector, WavePhase, WaveSettings},
+};
+
+pub fn reset_wave_director_system(mut commands: Commands, settings: Res<WaveSettings>) {
+    commands.insert_resource(WaveDirector::new(&settings));
+}
+
+/// Runs the waves: rests between them, starts the next one with a scaled budget, spends the
+/// budget on enemies and ends the wave once it is spent and cleared. Spawns wait while the
+/// population is at its cap or no spawn point is free.
+#[allow(clippy::too_many_arguments)]
+pub fn wave_director_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<WaveSettings>,
+    registry: Res<EnemyRegistry>,
+    weapon_registry: Res<WeaponRegistry>,
+    telegraph_assets: Res<SpawnTelegraphAssets>,
+    mut population: ResMut<Population>,
+    mut spawn_points: ResMut<SpawnPoints>,
+    mut director: ResMut<WaveDirector>,
+    player_query: Query<(), With<Player>>,
+    weapon_query: Query<&Weapon>,
+    member_query: Query<&WaveMember>,
+    mut started_events: EventWriter<WaveStartedEvent>,
+    mut ended_events: EventWriter<WaveEndedEvent>,
+) {
+    if player_query.is_empty() {
+        return;
+    }
+    director.run_seconds += time.delta_secs();
+
+    let director = director.as_mut();
+    match &mut director.phase {
+        WavePhase::Resting(timer) => {
+            if !timer.tick(time.delta()).finished() {
+                return;
+            }
+            let player_dps = weapon_query
+                .get_single()
+                .ok()
+                .and_then(|weapon| weapon_registry.get(weapon.item))
+                .map_or(0.0, |definition| definition.damage_per_second());
+            director.start_wave(&settings, player_dps);
+            info!(
+                "Wave {} started with a budget of {}",
+                director.wave, director.budget
+            );
+            started_events.send(WaveStartedEvent {
+                wave: director.wave,
+            });
+        }
+        WavePhase::Active {
+            definition,
+            elapsed,
+        } => {
+            *elapsed += time.delta_secs();
+            // Boss waves last until the boss and its wave are dead.
+            let timed_out = *elapsed >= settings.max_wave_seconds && definition.boss.is_none();
+
+            if director.budget_left == 0 || timed_out {
+                let wave = director.wave;
+                let cleared = !member_query.iter().any(|member| member.0 == wave);
+                if cleared || timed_out {
+                    director.end_wave(&settings);
+                    ended_events.send(WaveEndedEvent {
+                        wave,
+                        rest_seconds: settings.rest_seconds,
+                    });
+                }
+                return;
+            }
+
+            // The timer stays finished, so a held back spawn goes out as soon as there is room.
+            if !director.spawn_timer.tick(time.delta()).finished()
+                || population.active >= settings.population.max_active_enemies
+            {
+                return;
+            }
+            let mut rng = thread_rng();
+            let Some(archetype) =
+                definition.pick_archetype(&registry, director.budget_left, &mut rng)
+            else {
+                // Nothing in the composition is cheap enough for what is left.
+                director.budget_left = 0;
+                return;
+            };
+
+            let Some(spawn_position) = spawn_points.take(&mut rng) else {
+                return;
+            };
+            let telegraph = spawn_telegraph(
+                &mut commands,
+                &telegraph_assets,
+                &settings.population,
+                archetype,
+                archetype.health * settings.scaling.health_multiplier(director.run_seconds),
+                spawn_position,
+            );
+            commands.entity(telegraph).insert(WaveMember(director.wave));
+            population.active += 1;
+
+            let spent = director.budget - director.budget_left.saturating_sub(archetype.cost);
+            let next_interval = definition.spawn_interval_at(spent as f32 / director.budget as f32);
+            director.spend(archetype.cost, next_interval);
+        }
+    }
+}
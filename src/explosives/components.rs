@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExplosiveKind {
+    Grenade,
+    Barrel,
+}
+
+/// Blows up once its `Fuse` burns down.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Explosive(pub ExplosiveKind);
+
+/// Present on explosives that are about to go off.
+#[derive(Component)]
+pub struct Fuse(pub Timer);
+
+/// Barrel placed by world generation, it is lit by gunfire and by nearby explosions.
+#[derive(Component)]
+pub struct ExplosiveBarrel;
+
+/// A thrown grenade flying from `start` to `target` in a fake arc.
+#[derive(Component)]
+pub struct Grenade {
+    pub start: Vec2,
+    pub target: Vec2,
+    pub flight: Timer,
+    pub arc_height: f32,
+}
+
+impl Grenade {
+    /// Height above the ground at `progress` through the flight, a parabola peaking halfway.
+    pub fn height(&self, progress: f32) -> f32 {
+        4.0 * self.arc_height * progress * (1.0 - progress)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grenade_arc_lands_on_the_ground() {
+        let grenade = Grenade {
+            start: Vec2::ZERO,
+            target: Vec2::X * 100.0,
+            flight: Timer::from_seconds(0.5, TimerMode::Once),
+            arc_height: 40.0,
+        };
+
+        assert_eq!(grenade.height(0.0), 0.0);
+        assert_eq!(grenade.height(0.5), 40.0);
+        assert_eq!(grenade.height(1.0), 0.0);
+    }
+}
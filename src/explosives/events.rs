@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+use super::components::ExplosiveKind;
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ExplosionEvent {
+    pub position: Vec2,
+    pub kind: ExplosiveKind,
+}
@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+use events::ExplosionEvent;
+use resources::ExplosiveSettings;
+use systems::*;
+
+use crate::{inventory::InventoryScreenState, AppState};
+
+pub mod components;
+pub mod events;
+pub mod resources;
+mod systems;
+
+/// Grenades thrown with G and barrels that blow up when shot.
+pub struct ExplosivesPlugin;
+
+impl Plugin for ExplosivesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ExplosionEvent>();
+        app.insert_resource(ExplosiveSettings::load());
+        app.add_systems(Startup, load_explosive_assets);
+        app.add_systems(
+            Update,
+            (
+                setup_explosive_barrel_system,
+                throw_grenade_system.run_if(in_state(InventoryScreenState::Closed)),
+                grenade_flight_system,
+                ignite_shot_barrels_system,
+                barrel_fuse_animation_system,
+                fuse_system,
+                explosion_system,
+            )
+                .chain()
+                .run_if(in_state(AppState::InGame)),
+        );
+        app.add_systems(OnExit(AppState::InGame), despawn_grenades_system);
+    }
+}
@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use super::components::ExplosiveKind;
+use crate::{
+    player::weapon::{
+        components::{DamageFalloff, ImpactEffect},
+        resources::EffectDefinition,
+    },
+    sprites::SPRITE_FOLDER,
+    status_effects::components::StatusEffect,
+};
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct ExplosionSettings {
+    pub radius: f32,
+    /// Enemies closer than this take the full damage.
+    pub full_damage_radius: f32,
+    pub damage: f32,
+    /// Fraction of the damage dealt at the edge of the explosion.
+    pub min_damage_multiplier: f32,
+    /// Multiplier on how far hit characters are pushed back.
+    pub knockback: f32,
+    /// Hearts the player loses when caught in the explosion.
+    pub player_damage: u8,
+    pub camera_shake: f32,
+    /// Effect applied to every character caught in the explosion.
+    #[serde(default)]
+    pub status_effect: Option<StatusEffect>,
+}
+
+impl ExplosionSettings {
+    pub fn falloff(&self) -> DamageFalloff {
+        DamageFalloff {
+            start_distance: self.full_damage_radius,
+            end_distance: self.radius,
+            min_multiplier: self.min_damage_multiplier,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GrenadeSettings {
+    pub fuse_seconds: f32,
+    /// Grenades thrown at the cursor land at most this far from the player.
+    pub throw_range: f32,
+    pub flight_seconds: f32,
+    /// Highest point of the throw above the ground.
+    pub arc_height: f32,
+    pub explosion: ExplosionSettings,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BarrelSettings {
+    pub fuse_seconds: f32,
+    /// Played over the fuse, the first frame is the intact barrel.
+    pub frames: Vec<String>,
+    pub explosion: ExplosionSettings,
+}
+
+#[derive(Resource, Debug, Deserialize)]
+pub struct ExplosiveSettings {
+    pub grenade: GrenadeSettings,
+    pub barrel: BarrelSettings,
+    pub explosion_effect: EffectDefinition,
+    pub smoke_effect: EffectDefinition,
+}
+
+impl ExplosiveSettings {
+    pub fn load() -> Self {
+        ron::from_str(include_str!("../../assets/data/explosives.ron"))
+            .expect("assets/data/explosives.ron should contain valid explosive settings")
+    }
+
+    pub fn explosion(&self, kind: ExplosiveKind) -> &ExplosionSettings {
+        match kind {
+            ExplosiveKind::Grenade => &self.grenade.explosion,
+            ExplosiveKind::Barrel => &self.barrel.explosion,
+        }
+    }
+}
+
+impl BarrelSettings {
+    pub fn frame_paths(&self) -> impl Iterator<Item = String> + '_ {
+        self.frames
+            .iter()
+            .map(|frame| format!("{}/{}", SPRITE_FOLDER, frame))
+    }
+}
+
+/// Images of grenades, barrels and explosions, loaded once at startup.
+#[derive(Resource)]
+pub struct ExplosiveAssets {
+    pub barrel_frames: Vec<Handle<Image>>,
+    pub explosion: ImpactEffect,
+    pub smoke: ImpactEffect,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explosive_settings_load() {
+        let settings = ExplosiveSettings::load();
+
+        for explosion in [settings.grenade.explosion, settings.barrel.explosion] {
+            assert!(explosion.full_damage_radius < explosion.radius);
+            assert_eq!(explosion.falloff().multiplier(0.0), 1.0);
+            assert_eq!(
+                explosion.falloff().multiplier(explosion.radius),
+                explosion.min_damage_multiplier
+            );
+        }
+        assert!(!settings.barrel.frames.is_empty());
+    }
+}
@@ -0,0 +1,312 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+use crate::{
+    camera::events::CameraShakeEvent,
+    collision::GameLayer,
+    enemy::{
+        components::{Enemy, EnemyHealth},
+        spatial::resources::SpatialHash,
+    },
+    hit_feedback::events::{DamageEvent, DamageSource, HitEvent},
+    inventory::{components::Inventory, items::ItemId},
+    player::{
+        character::components::PassiveAbility,
+        components::{DamageInvulnerability, Player, PlayerHealth},
+        weapon::{
+            load_effect,
+            pool::{play_effect, EffectPool},
+            resources::WeaponTrigger,
+        },
+    },
+    status_effects::events::ApplyStatusEffectEvent,
+};
+
+use super::{
+    components::{Explosive, ExplosiveBarrel, ExplosiveKind, Fuse, Grenade},
+    events::ExplosionEvent,
+    resources::{ExplosiveAssets, ExplosiveSettings},
+};
+
+const BARREL_SCALE: f32 = 2.5;
+const GRENADE_SCALE: f32 = 2.0;
+const GRENADE_Z: f32 = 8.0;
+const EXPLOSION_SCALE: f32 = 8.0;
+const SMOKE_SCALE: f32 = 5.0;
+const EFFECT_Z: f32 = 10.0;
+
+pub fn load_explosive_assets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<ExplosiveSettings>,
+) {
+    commands.insert_resource(ExplosiveAssets {
+        barrel_frames: settings
+            .barrel
+            .frame_paths()
+            .map(|path| asset_server.load(path))
+            .collect(),
+        explosion: load_effect(&asset_server, Some(&settings.explosion_effect)),
+        smoke: load_effect(&asset_server, Some(&settings.smoke_effect)),
+    });
+}
+
+/// World generation only places the `ExplosiveBarrel` marker, this gives new barrels their
+/// sprite and a solid collider characters can't walk through.
+pub fn setup_explosive_barrel_system(
+    mut commands: Commands,
+    explosive_assets: Res<ExplosiveAssets>,
+    mut barrel_query: Query<(Entity, &mut Transform), Added<ExplosiveBarrel>>,
+) {
+    for (entity, mut transform) in barrel_query.iter_mut() {
+        transform.scale = Vec3::splat(BARREL_SCALE);
+        commands.entity(entity).insert((
+            Explosive(ExplosiveKind::Barrel),
+            Sprite {
+                image: explosive_assets
+                    .barrel_frames
+                    .first()
+                    .cloned()
+                    .unwrap_or_default(),
+                ..default()
+            },
+            RigidBody::Static,
+            Collider::rectangle(10.0, 14.0),
+            CollisionLayers::new(
+                GameLayer::TERRAIN,
+                [
+                    GameLayer::PLAYER,
+                    GameLayer::ENEMY,
+                    GameLayer::PROJECTILE,
+                    GameLayer::ENEMY_PROJECTILE,
+                ],
+            ),
+            Name::new("ExplosiveBarrel"),
+        ));
+    }
+}
+
+pub fn throw_grenade_system(
+    mut commands: Commands,
+    key_input: Res<ButtonInput<KeyCode>>,
+    asset_server: Res<AssetServer>,
+    trigger: Res<WeaponTrigger>,
+    settings: Res<ExplosiveSettings>,
+    mut player_query: Query<(&Transform, &mut Inventory), With<Player>>,
+) {
+    if !key_input.just_pressed(KeyCode::KeyG) {
+        return;
+    }
+    let Ok((player_transform, mut inventory)) = player_query.get_single_mut() else {
+        return;
+    };
+    if inventory.remove_item(ItemId::Grenade, 1) == 0 {
+        return;
+    }
+
+    let grenade = &settings.grenade;
+    let start = player_transform.translation.truncate();
+    let target = start + (trigger.target - start).clamp_length_max(grenade.throw_range);
+    commands.spawn((
+        Explosive(ExplosiveKind::Grenade),
+        Grenade {
+            start,
+            target,
+            flight: Timer::from_seconds(grenade.flight_seconds, TimerMode::Once),
+            arc_height: grenade.arc_height,
+        },
+        Fuse(Timer::from_seconds(grenade.fuse_seconds, TimerMode::Once)),
+        Sprite {
+            image: asset_server.load(ItemId::Grenade.pickup_sprite_path()),
+            ..default()
+        },
+        Transform::from_translation(start.extend(GRENADE_Z)).with_scale(Vec3::splat(GRENADE_SCALE)),
+        Name::new("Grenade"),
+    ));
+}
+
+/// Moves grenades along the ground towards their target and lifts the sprite by the height
+/// of the arc, spinning it until it lands.
+pub fn grenade_flight_system(
+    time: Res<Time>,
+    mut grenade_query: Query<(&mut Grenade, &mut Transform)>,
+) {
+    for (mut grenade, mut transform) in grenade_query.iter_mut() {
+        if grenade.flight.finished() {
+            continue;
+        }
+        let progress = grenade.flight.tick(time.delta()).fraction();
+        let ground = grenade.start.lerp(grenade.target, progress);
+        transform.translation = (ground + Vec2::Y * grenade.height(progress)).extend(GRENADE_Z);
+        transform.rotation = Quat::from_rotation_z(progress * std::f32::consts::TAU * 2.0);
+    }
+}
+
+/// Lights barrels that were shot.
+pub fn ignite_shot_barrels_system(
+    mut commands: Commands,
+    settings: Res<ExplosiveSettings>,
+    mut hit_events: EventReader<HitEvent>,
+    barrel_query: Query<(), (With<ExplosiveBarrel>, Without<Fuse>)>,
+) {
+    for event in hit_events.read() {
+        if barrel_query.contains(event.target) {
+            commands
+                .entity(event.target)
+                .insert(Fuse(Timer::from_seconds(
+                    settings.barrel.fuse_seconds,
+                    TimerMode::Once,
+                )));
+        }
+    }
+}
+
+pub fn barrel_fuse_animation_system(
+    explosive_assets: Res<ExplosiveAssets>,
+    mut barrel_query: Query<(&Fuse, &mut Sprite), With<ExplosiveBarrel>>,
+) {
+    let frames = &explosive_assets.barrel_frames;
+    let Some(last_frame) = frames.len().checked_sub(1) else {
+        return;
+    };
+
+    for (fuse, mut sprite) in barrel_query.iter_mut() {
+        let frame = (fuse.0.fraction() * frames.len() as f32) as usize;
+        sprite.image = frames[frame.min(last_frame)].clone();
+    }
+}
+
+pub fn fuse_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut explosive_query: Query<(Entity, &Explosive, &Transform, &mut Fuse)>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+) {
+    for (entity, explosive, transform, mut fuse) in explosive_query.iter_mut() {
+        if !fuse.0.tick(time.delta()).finished() {
+            continue;
+        }
+        explosion_events.send(ExplosionEvent {
+            position: transform.translation.truncate(),
+            kind: explosive.0,
+        });
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Damages and pushes back everything within the blast radius and lights nearby barrels,
+/// which makes them go off a moment later.
+#[allow(clippy::too_many_arguments)]
+pub fn explosion_system(
+    mut commands: Commands,
+    settings: Res<ExplosiveSettings>,
+    explosive_assets: Res<ExplosiveAssets>,
+    spatial_query: SpatialQuery,
+    spatial_hash: Res<SpatialHash>,
+    mut effect_pool: ResMut<EffectPool>,
+    mut enemy_query: Query<(&mut EnemyHealth, &GlobalTransform), With<Enemy>>,
+    mut player_query: Query<
+        (&mut PlayerHealth, &GlobalTransform, Option<&PassiveAbility>),
+        (With<Player>, Without<DamageInvulnerability>),
+    >,
+    barrel_query: Query<(), (With<ExplosiveBarrel>, Without<Fuse>)>,
+    mut explosion_events: EventReader<ExplosionEvent>,
+    mut hit_events: EventWriter<HitEvent>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut shake_events: EventWriter<CameraShakeEvent>,
+    mut status_effect_events: EventWriter<ApplyStatusEffectEvent>,
+) {
+    let mut player_damaged = false;
+
+    for event in explosion_events.read() {
+        let explosion = settings.explosion(event.kind);
+        let falloff = explosion.falloff();
+
+        for (entity, _) in spatial_hash.within(event.position, explosion.radius) {
+            if let Ok((mut enemy_health, transform)) = enemy_query.get_mut(entity) {
+                let offset = transform.translation().truncate() - event.position;
+                let damage = explosion.damage * falloff.multiplier(offset.length());
+                enemy_health.0 -= damage;
+                damage_events.send(DamageEvent {
+                    target: entity,
+                    amount: damage,
+                    source: DamageSource::Explosion,
+                    dealer: None,
+                    position: transform.translation().truncate(),
+                    critical: false,
+                    lethal: enemy_health.0 <= 0.0,
+                });
+                hit_events.send(HitEvent {
+                    target: entity,
+                    direction: offset,
+                    force: explosion.knockback,
+                });
+                if let Some(effect) = explosion.status_effect {
+                    status_effect_events.send(ApplyStatusEffectEvent {
+                        target: entity,
+                        effect,
+                    });
+                }
+            }
+        }
+
+        let caught = spatial_query.shape_intersections(
+            &Collider::circle(explosion.radius),
+            event.position,
+            0.0,
+            &SpatialQueryFilter::from_mask([GameLayer::PLAYER, GameLayer::TERRAIN]),
+        );
+        for entity in caught {
+            if let Ok((mut player_health, transform, passive)) = player_query.get_mut(entity) {
+                if player_damaged {
+                    continue;
+                }
+                player_damaged = true;
+                player_health.decrease_health(explosion.player_damage);
+                hit_events.send(HitEvent {
+                    target: entity,
+                    direction: transform.translation().truncate() - event.position,
+                    force: explosion.knockback,
+                });
+                commands
+                    .entity(entity)
+                    .insert(PassiveAbility::damage_invulnerability(passive));
+                if let Some(effect) = explosion.status_effect {
+                    status_effect_events.send(ApplyStatusEffectEvent {
+                        target: entity,
+                        effect,
+                    });
+                }
+            } else if barrel_query.contains(entity) {
+                commands.entity(entity).insert(Fuse(Timer::from_seconds(
+                    settings.barrel.fuse_seconds,
+                    TimerMode::Once,
+                )));
+            }
+        }
+
+        for (effect, scale) in [
+            (&explosive_assets.smoke, SMOKE_SCALE),
+            (&explosive_assets.explosion, EXPLOSION_SCALE),
+        ] {
+            play_effect(
+                &mut commands,
+                &mut effect_pool,
+                &effect.frames,
+                effect.frame_seconds,
+                Transform::from_translation(event.position.extend(EFFECT_Z))
+                    .with_scale(Vec3::splat(scale)),
+            );
+        }
+        shake_events.send(CameraShakeEvent(explosion.camera_shake));
+    }
+}
+
+pub fn despawn_grenades_system(
+    mut commands: Commands,
+    grenade_query: Query<Entity, With<Grenade>>,
+) {
+    for entity in grenade_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
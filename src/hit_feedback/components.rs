@@ -0,0 +1,132 @@
+use bevy::prelude::*;
+
+use super::events::DamageEvent;
+
+/// How an entity reacts to being hit. Every damageable entity type gets its own values.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct HitReaction {
+    pub knockback_strength: f32,
+    pub knockback_duration: f32,
+    pub flash_duration: f32,
+    pub flash_color: Color,
+    /// Seconds the game slows down for when this entity is hit. Zero disables hit-stop.
+    pub hit_stop: f32,
+}
+
+impl HitReaction {
+    pub fn player() -> Self {
+        Self {
+            knockback_strength: 250.0,
+            knockback_duration: 0.15,
+            flash_duration: 0.2,
+            flash_color: Color::srgb(1.0, 0.3, 0.3),
+            hit_stop: 0.08,
+        }
+    }
+}
+
+/// Velocity pushed onto an entity after a hit. Movement systems use it instead of steering
+/// until the timer runs out.
+#[derive(Component, Debug)]
+pub struct Knockback {
+    pub velocity: Vec2,
+    pub timer: Timer,
+}
+
+impl Knockback {
+    pub fn current_velocity(&self) -> Vec2 {
+        self.velocity * self.timer.fraction_remaining()
+    }
+}
+
+/// Present while an entity shows its hit flash and damaged animation frames.
+#[derive(Component, Debug)]
+pub struct HitFlash {
+    pub timer: Timer,
+}
+
+/// Floating number showing the damage an enemy took. Hits landing on the same enemy while
+/// `merge_window` runs are added to it instead of spawning more numbers.
+#[derive(Component, Debug)]
+pub struct DamageNumber {
+    pub target: Entity,
+    pub amount: f32,
+    pub critical: bool,
+    pub lethal: bool,
+    pub merge_window: Timer,
+    pub lifetime: Timer,
+}
+
+impl DamageNumber {
+    pub fn new(event: &DamageEvent, merge_seconds: f32, lifetime_seconds: f32) -> Self {
+        Self {
+            target: event.target,
+            amount: event.amount,
+            critical: event.critical,
+            lethal: event.lethal,
+            merge_window: Timer::from_seconds(merge_seconds, TimerMode::Once),
+            lifetime: Timer::from_seconds(lifetime_seconds, TimerMode::Once),
+        }
+    }
+
+    pub fn accepts(&self, event: &DamageEvent) -> bool {
+        self.target == event.target && !self.merge_window.finished()
+    }
+
+    /// Adds another hit and keeps the number on screen for its full lifetime again.
+    pub fn merge(&mut self, event: &DamageEvent) {
+        self.amount += event.amount;
+        self.critical |= event.critical;
+        self.lethal |= event.lethal;
+        self.lifetime.reset();
+    }
+
+    pub fn label(&self) -> String {
+        let amount = self.amount.round().max(1.0);
+        if self.critical {
+            format!("{}!", amount)
+        } else {
+            amount.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hit_feedback::events::DamageSource;
+
+    fn damage_event(amount: f32, critical: bool) -> DamageEvent {
+        DamageEvent {
+            target: Entity::PLACEHOLDER,
+            amount,
+            source: DamageSource::Projectile,
+            dealer: None,
+            position: Vec2::ZERO,
+            critical,
+            lethal: false,
+        }
+    }
+
+    #[test]
+    fn test_damage_number_merges_hits() {
+        let mut number = DamageNumber::new(&damage_event(12.0, false), 0.2, 0.8);
+
+        number.merge(&damage_event(30.4, true));
+
+        assert_eq!(number.amount, 42.4);
+        assert!(number.critical);
+        assert_eq!(number.label(), "42!");
+    }
+
+    #[test]
+    fn test_damage_number_stops_merging_after_window() {
+        let mut number = DamageNumber::new(&damage_event(5.0, false), 0.2, 0.8);
+
+        number
+            .merge_window
+            .tick(std::time::Duration::from_secs_f32(0.3));
+
+        assert!(!number.accepts(&damage_event(5.0, false)));
+    }
+}
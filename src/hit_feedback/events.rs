@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct HitEvent {
+    pub target: Entity,
+    /// Direction the hit pushes the target in.
+    pub direction: Vec2,
+    /// Multiplier on the target's knockback strength.
+    pub force: f32,
+}
+
+/// What took health from an enemy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageSource {
+    Projectile,
+    Melee,
+    Explosion,
+    StatusEffect,
+}
+
+/// Sent whenever an enemy loses health, shown as a floating damage number.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DamageEvent {
+    pub target: Entity,
+    pub amount: f32,
+    pub source: DamageSource,
+    /// Entity that dealt the damage, if it is known.
+    pub dealer: Option<Entity>,
+    /// Where the damage landed in world space.
+    pub position: Vec2,
+    pub critical: bool,
+    /// The hit left the target without health.
+    pub lethal: bool,
+}
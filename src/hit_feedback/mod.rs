@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use events::{DamageEvent, HitEvent};
+use resources::HitStop;
+use systems::*;
+
+use crate::AppState;
+
+pub mod components;
+pub mod events;
+pub mod resources;
+mod systems;
+
+pub struct HitFeedbackPlugin;
+
+impl Plugin for HitFeedbackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<HitEvent>()
+            .add_event::<DamageEvent>()
+            .init_resource::<HitStop>()
+            .add_systems(
+                Update,
+                (
+                    apply_hit_reaction_system,
+                    knockback_system,
+                    hit_flash_system,
+                    invulnerability_blink_system,
+                    restore_visibility_after_invulnerability_system,
+                    (spawn_damage_numbers_system, damage_number_system).chain(),
+                )
+                    .run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(OnExit(AppState::InGame), despawn_damage_numbers_system)
+            .add_systems(Update, hit_stop_system);
+    }
+}
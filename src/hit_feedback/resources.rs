@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+#[derive(Resource, Default)]
+pub struct HitStop {
+    pub remaining_seconds: f32,
+}
@@ -0,0 +1,208 @@
+use bevy::prelude::*;
+
+use crate::player::components::{DamageInvulnerability, Player};
+
+use super::{
+    components::{DamageNumber, HitFlash, HitReaction, Knockback},
+    events::{DamageEvent, HitEvent},
+    resources::HitStop,
+};
+
+const HIT_STOP_TIME_SCALE: f32 = 0.05;
+const BLINK_INTERVAL_SECONDS: f32 = 0.1;
+/// Hits on one enemy within this window are shown as a single number.
+const DAMAGE_NUMBER_MERGE_SECONDS: f32 = 0.25;
+const DAMAGE_NUMBER_LIFETIME_SECONDS: f32 = 0.8;
+const DAMAGE_NUMBER_RISE_SPEED: f32 = 40.0;
+const DAMAGE_NUMBER_Z: f32 = 20.0;
+const DAMAGE_NUMBER_COLOR: Color = Color::WHITE;
+const CRITICAL_DAMAGE_NUMBER_COLOR: Color = Color::srgb(1.0, 0.8, 0.1);
+const LETHAL_DAMAGE_NUMBER_COLOR: Color = Color::srgb(0.95, 0.2, 0.2);
+
+pub fn apply_hit_reaction_system(
+    mut commands: Commands,
+    mut hit_events: EventReader<HitEvent>,
+    mut target_query: Query<(&HitReaction, &mut Sprite)>,
+    mut hit_stop: ResMut<HitStop>,
+) {
+    for event in hit_events.read() {
+        let Ok((reaction, mut sprite)) = target_query.get_mut(event.target) else {
+            continue;
+        };
+        let Some(mut target_commands) = commands.get_entity(event.target) else {
+            continue;
+        };
+
+        target_commands.insert((
+            Knockback {
+                velocity: event.direction.normalize_or_zero()
+                    * reaction.knockback_strength
+                    * event.force,
+                timer: Timer::from_seconds(reaction.knockback_duration, TimerMode::Once),
+            },
+            HitFlash {
+                timer: Timer::from_seconds(reaction.flash_duration, TimerMode::Once),
+            },
+        ));
+        sprite.color = reaction.flash_color;
+        hit_stop.remaining_seconds = hit_stop.remaining_seconds.max(reaction.hit_stop);
+    }
+}
+
+pub fn knockback_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut knockback_query: Query<(Entity, &mut Knockback)>,
+) {
+    for (entity, mut knockback) in knockback_query.iter_mut() {
+        if knockback.timer.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<Knockback>();
+        }
+    }
+}
+
+pub fn hit_flash_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut flash_query: Query<(Entity, &mut HitFlash, &mut Sprite)>,
+) {
+    for (entity, mut flash, mut sprite) in flash_query.iter_mut() {
+        if flash.timer.tick(time.delta()).finished() {
+            sprite.color = Color::WHITE;
+            commands.entity(entity).remove::<HitFlash>();
+        }
+    }
+}
+
+/// Slows down virtual time for a moment after heavy hits. Runs on real time so it can
+/// recover from its own slowdown.
+pub fn hit_stop_system(
+    real_time: Res<Time<Real>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut hit_stop: ResMut<HitStop>,
+) {
+    if hit_stop.remaining_seconds > 0.0 {
+        virtual_time.set_relative_speed(HIT_STOP_TIME_SCALE);
+        hit_stop.remaining_seconds -= real_time.delta_secs();
+    } else if virtual_time.relative_speed() != 1.0 {
+        virtual_time.set_relative_speed(1.0);
+    }
+}
+
+pub fn invulnerability_blink_system(
+    mut player_query: Query<(&DamageInvulnerability, &mut Visibility), With<Player>>,
+) {
+    for (invulnerability, mut visibility) in player_query.iter_mut() {
+        let blink_phase = (invulnerability.timer.elapsed_secs() / BLINK_INTERVAL_SECONDS) as u32;
+        *visibility = if blink_phase % 2 == 0 {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+pub fn restore_visibility_after_invulnerability_system(
+    mut removed_invulnerability: RemovedComponents<DamageInvulnerability>,
+    mut visibility_query: Query<&mut Visibility>,
+) {
+    for entity in removed_invulnerability.read() {
+        if let Ok(mut visibility) = visibility_query.get_mut(entity) {
+            *visibility = Visibility::Inherited;
+        }
+    }
+}
+
+/// Adds damage to the number still showing above an enemy or spawns a new one at the
+/// impact point.
+pub fn spawn_damage_numbers_system(
+    mut commands: Commands,
+    mut damage_events: EventReader<DamageEvent>,
+    mut number_query: Query<&mut DamageNumber>,
+) {
+    // Hits from this frame are collected first, so several hits in one frame share a number.
+    let mut new_numbers: Vec<(DamageNumber, Vec2)> = Vec::new();
+
+    for event in damage_events.read() {
+        if let Some(mut number) = number_query.iter_mut().find(|number| number.accepts(event)) {
+            number.merge(event);
+        } else if let Some((number, _)) = new_numbers
+            .iter_mut()
+            .find(|(number, _)| number.accepts(event))
+        {
+            number.merge(event);
+        } else {
+            new_numbers.push((
+                DamageNumber::new(
+                    event,
+                    DAMAGE_NUMBER_MERGE_SECONDS,
+                    DAMAGE_NUMBER_LIFETIME_SECONDS,
+                ),
+                event.position,
+            ));
+        }
+    }
+
+    for (number, position) in new_numbers {
+        commands.spawn((
+            Text2d::new(number.label()),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(DAMAGE_NUMBER_COLOR),
+            Transform::from_translation(position.extend(DAMAGE_NUMBER_Z)),
+            number,
+            Name::new("DamageNumber"),
+        ));
+    }
+}
+
+/// Lets damage numbers rise and fade out. Crits are drawn larger, numbers of kills in red.
+pub fn damage_number_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut number_query: Query<(
+        Entity,
+        &mut DamageNumber,
+        &mut Transform,
+        &mut Text2d,
+        &mut TextFont,
+        &mut TextColor,
+    )>,
+) {
+    for (entity, mut number, mut transform, mut text, mut font, mut color) in
+        number_query.iter_mut()
+    {
+        number.merge_window.tick(time.delta());
+        if number.lifetime.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        transform.translation.y += DAMAGE_NUMBER_RISE_SPEED * time.delta_secs();
+        text.0 = number.label();
+        font.font_size = if number.critical || number.lethal {
+            20.0
+        } else {
+            14.0
+        };
+        let base_color = if number.lethal {
+            LETHAL_DAMAGE_NUMBER_COLOR
+        } else if number.critical {
+            CRITICAL_DAMAGE_NUMBER_COLOR
+        } else {
+            DAMAGE_NUMBER_COLOR
+        };
+        color.0 = base_color.with_alpha(number.lifetime.fraction_remaining());
+    }
+}
+
+pub fn despawn_damage_numbers_system(
+    mut commands: Commands,
+    number_query: Query<Entity, With<DamageNumber>>,
+) {
+    for entity in number_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,244 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::items::ItemId;
+
+pub const INVENTORY_SIZE: usize = 15;
+pub const HOTBAR_SIZE: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemStack {
+    pub item: ItemId,
+    pub count: u32,
+}
+
+impl ItemStack {
+    pub fn new(item: ItemId, count: u32) -> Self {
+        Self { item, count }
+    }
+}
+
+/// An item lying in the world, walking over it moves it into the player's inventory.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct WorldItem(pub ItemStack);
+
+/// The first `HOTBAR_SIZE` slots double as the hotbar.
+#[derive(Component, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Inventory {
+    slots: Vec<Option<ItemStack>>,
+    selected_slot: usize,
+}
+
+impl Default for Inventory {
+    fn default() -> Self {
+        Self::new(INVENTORY_SIZE)
+    }
+}
+
+impl Inventory {
+    pub fn new(size: usize) -> Self {
+        Self {
+            slots: vec![None; size.max(HOTBAR_SIZE)],
+            selected_slot: 0,
+        }
+    }
+
+    pub fn with_items(items: &[(ItemId, u32)]) -> Self {
+        let mut inventory = Self::default();
+        for (item, count) in items {
+            inventory.add_item(*item, *count);
+        }
+        inventory
+    }
+
+    /// Fills up existing stacks of the item first, then empty slots.
+    /// Returns the amount that did not fit.
+    pub fn add_item(&mut self, item: ItemId, count: u32) -> u32 {
+        let max_stack = item.definition().max_stack;
+        let mut remaining = count;
+
+        for stack in self.slots.iter_mut().flatten() {
+            if remaining == 0 {
+                break;
+            }
+            if stack.item == item && stack.count < max_stack {
+                let added = remaining.min(max_stack - stack.count);
+                stack.count += added;
+                remaining -= added;
+            }
+        }
+
+        for slot in self.slots.iter_mut() {
+            if remaining == 0 {
+                break;
+            }
+            if slot.is_none() {
+                let added = remaining.min(max_stack);
+                *slot = Some(ItemStack::new(item, added));
+                remaining -= added;
+            }
+        }
+
+        remaining
+    }
+
+    /// Removes up to `count` items from a slot and returns what was actually removed.
+    pub fn remove_from_slot(&mut self, slot: usize, count: u32) -> Option<ItemStack> {
+        let stack = self.slots.get_mut(slot)?.as_mut()?;
+        let removed = ItemStack::new(stack.item, count.min(stack.count));
+        stack.count -= removed.count;
+        if stack.count == 0 {
+            self.slots[slot] = None;
+        }
+        Some(removed)
+    }
+
+    /// Removes up to `count` items of a kind across all slots. Returns the amount removed.
+    pub fn remove_item(&mut self, item: ItemId, count: u32) -> u32 {
+        let mut removed = 0;
+        for index in 0..self.slots.len() {
+            if removed == count {
+                break;
+            }
+            if matches!(self.slots[index], Some(stack) if stack.item == item) {
+                removed += self
+                    .remove_from_slot(index, count - removed)
+                    .map_or(0, |stack| stack.count);
+            }
+        }
+        removed
+    }
+
+    pub fn count(&self, item: ItemId) -> u32 {
+        self.slots
+            .iter()
+            .flatten()
+            .filter(|stack| stack.item == item)
+            .map(|stack| stack.count)
+            .sum()
+    }
+
+    pub fn slots(&self) -> &[Option<ItemStack>] {
+        &self.slots
+    }
+
+    pub fn selected_slot(&self) -> usize {
+        self.selected_slot
+    }
+
+    pub fn selected_stack(&self) -> Option<ItemStack> {
+        self.slots[self.selected_slot]
+    }
+
+    pub fn select_hotbar_slot(&mut self, slot: usize) {
+        if slot < HOTBAR_SIZE {
+            self.selected_slot = slot;
+        }
+    }
+
+    /// Moves the selection through the hotbar, wrapping around at both ends.
+    pub fn cycle_hotbar(&mut self, steps: i32) {
+        let slot = (self.selected_slot as i32 + steps).rem_euclid(HOTBAR_SIZE as i32);
+        self.selected_slot = slot as usize;
+    }
+
+    pub fn swap_slots(&mut self, a: usize, b: usize) {
+        if a < self.slots.len() && b < self.slots.len() {
+            self.slots.swap(a, b);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_item_stacks_up_to_max() {
+        let mut inventory = Inventory::default();
+        let max_stack = ItemId::Medicine.definition().max_stack;
+
+        let leftover = inventory.add_item(ItemId::Medicine, max_stack + 2);
+
+        assert_eq!(leftover, 0);
+        assert_eq!(
+            inventory.slots()[0],
+            Some(ItemStack::new(ItemId::Medicine, max_stack))
+        );
+        assert_eq!(inventory.slots()[1], Some(ItemStack::new(ItemId::Medicine, 2)));
+        assert_eq!(inventory.count(ItemId::Medicine), max_stack + 2);
+    }
+
+    #[test]
+    fn test_add_item_fills_existing_stack_first() {
+        let mut inventory = Inventory::with_items(&[(ItemId::Coke, 1), (ItemId::Pistol, 1)]);
+
+        inventory.add_item(ItemId::Coke, 2);
+
+        assert_eq!(inventory.slots()[0], Some(ItemStack::new(ItemId::Coke, 3)));
+        assert_eq!(inventory.slots()[2], None);
+    }
+
+    #[test]
+    fn test_add_item_returns_leftover_when_full() {
+        let mut inventory = Inventory::new(HOTBAR_SIZE);
+
+        let leftover = inventory.add_item(ItemId::Radio, HOTBAR_SIZE as u32 + 3);
+
+        assert_eq!(leftover, 3, "Radios do not stack, so only one fits per slot");
+    }
+
+    #[test]
+    fn test_remove_from_slot_clears_empty_stack() {
+        let mut inventory = Inventory::with_items(&[(ItemId::HealthKit, 1)]);
+
+        let removed = inventory.remove_from_slot(0, 5);
+
+        assert_eq!(removed, Some(ItemStack::new(ItemId::HealthKit, 1)));
+        assert_eq!(inventory.slots()[0], None);
+        assert_eq!(inventory.remove_from_slot(0, 1), None);
+    }
+
+    #[test]
+    fn test_remove_item_across_stacks() {
+        let max_stack = ItemId::Medicine.definition().max_stack;
+        let mut inventory = Inventory::with_items(&[(ItemId::Medicine, max_stack + 1)]);
+
+        let removed = inventory.remove_item(ItemId::Medicine, max_stack);
+
+        assert_eq!(removed, max_stack);
+        assert_eq!(inventory.count(ItemId::Medicine), 1);
+    }
+
+    #[test]
+    fn test_cycle_hotbar_wraps() {
+        let mut inventory = Inventory::default();
+
+        inventory.cycle_hotbar(-1);
+        assert_eq!(inventory.selected_slot(), HOTBAR_SIZE - 1);
+
+        inventory.cycle_hotbar(2);
+        assert_eq!(inventory.selected_slot(), 1);
+    }
+
+    #[test]
+    fn test_select_hotbar_slot_ignores_out_of_range() {
+        let mut inventory = Inventory::default();
+
+        inventory.select_hotbar_slot(2);
+        inventory.select_hotbar_slot(HOTBAR_SIZE);
+
+        assert_eq!(inventory.selected_slot(), 2);
+    }
+
+    #[test]
+    fn test_inventory_serialization_roundtrip() {
+        let mut inventory = Inventory::with_items(&[(ItemId::Pistol, 1), (ItemId::Coke, 4)]);
+        inventory.select_hotbar_slot(1);
+
+        let serialized = ron::to_string(&inventory).unwrap();
+        let deserialized: Inventory = ron::from_str(&serialized).unwrap();
+
+        assert_eq!(inventory, deserialized);
+    }
+}
@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+use super::items::ItemId;
+
+#[derive(Event)]
+pub struct ItemUsedEvent {
+    pub user: Entity,
+    pub item: ItemId,
+}
@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+
+const INVENTORY_SPRITES: &str =
+    "zombie_apocalypse_tileset/organized_separated_sprites/Inventory interface";
+const PICKUP_SPRITES: &str =
+    "zombie_apocalypse_tileset/organized_separated_sprites/Pickable Items and Weapons";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ItemId {
+    Pistol,
+    Smg,
+    Rifle,
+    Shotgun,
+    Grenade,
+    HealthKit,
+    Medicine,
+    Coke,
+    PistolAmmo,
+    RifleAmmo,
+    ShotgunShells,
+    GasCan,
+    Radio,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ItemKind {
+    Weapon,
+    Consumable(ConsumableEffect),
+    Key,
+    Material,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConsumableEffect {
+    Heal(u8),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ItemDefinition {
+    pub name: &'static str,
+    pub kind: ItemKind,
+    pub max_stack: u32,
+    /// Slot sprite in the inventory folder. Guns sit in an empty slot, the hotbar draws the
+    /// weapon's own sprite on top so they can be told apart.
+    pub icon: &'static str,
+    /// Sprite of the item lying in the world.
+    pub pickup_sprite: &'static str,
+}
+
+impl ItemId {
+    pub const ALL: &'static [ItemId] = &[
+        ItemId::Pistol,
+        ItemId::Smg,
+        ItemId::Rifle,
+        ItemId::Shotgun,
+        ItemId::Grenade,
+        ItemId::HealthKit,
+        ItemId::Medicine,
+        ItemId::Coke,
+        ItemId::PistolAmmo,
+        ItemId::RifleAmmo,
+        ItemId::ShotgunShells,
+        ItemId::GasCan,
+        ItemId::Radio,
+    ];
+
+    pub fn definition(&self) -> ItemDefinition {
+        match self {
+            ItemId::Pistol => ItemDefinition {
+                name: "Pistol",
+                kind: ItemKind::Weapon,
+                max_stack: 1,
+                icon: "EMPTY_SLOT.png",
+                pickup_sprite: "Zombie-Tileset---_0333_Capa-334.png",
+            },
+            ItemId::Smg => ItemDefinition {
+                name: "SMG",
+                kind: ItemKind::Weapon,
+                max_stack: 1,
+                icon: "EMPTY_SLOT.png",
+                pickup_sprite: "Zombie-Tileset---_0334_Capa-335.png",
+            },
+            ItemId::Rifle => ItemDefinition {
+                name: "Rifle",
+                kind: ItemKind::Weapon,
+                max_stack: 1,
+                icon: "EMPTY_SLOT.png",
+                pickup_sprite: "Zombie-Tileset---_0331_Capa-332.png",
+            },
+            ItemId::Shotgun => ItemDefinition {
+                name: "Shotgun",
+                kind: ItemKind::Weapon,
+                max_stack: 1,
+                icon: "EMPTY_SLOT.png",
+                pickup_sprite: "Zombie-Tileset---_0337_Capa-338.png",
+            },
+            ItemId::Grenade => ItemDefinition {
+                name: "Grenade",
+                kind: ItemKind::Weapon,
+                max_stack: 5,
+                icon: "GRENADE_SLOT.png",
+                pickup_sprite: "Zombie-Tileset---_0329_Capa-330.png",
+            },
+            ItemId::HealthKit => ItemDefinition {
+                name: "Health Kit",
+                kind: ItemKind::Consumable(ConsumableEffect::Heal(3)),
+                max_stack: 3,
+                icon: "HEALTH_KIT_SLOT.png",
+                pickup_sprite: "Zombie-Tileset---_0340_Capa-341.png",
+            },
+            ItemId::Medicine => ItemDefinition {
+                name: "Medicine",
+                kind: ItemKind::Consumable(ConsumableEffect::Heal(1)),
+                max_stack: 5,
+                icon: "MEDICINE_SLOT.png",
+                pickup_sprite: "Zombie-Tileset---_0342_Capa-343.png",
+            },
+            ItemId::Coke => ItemDefinition {
+                name: "Coke",
+                kind: ItemKind::Consumable(ConsumableEffect::Heal(1)),
+                max_stack: 10,
+                icon: "COKE_SLOT.png",
+                pickup_sprite: "Zombie-Tileset---_0341_Capa-342.png",
+            },
+            ItemId::PistolAmmo => ItemDefinition {
+                name: "Pistol Ammo",
+                kind: ItemKind::Material,
+                max_stack: 120,
+                icon: "SINGLE_AMMO_SLOT.png",
+                pickup_sprite: "Zombie-Tileset---_0344_Capa-345.png",
+            },
+            ItemId::RifleAmmo => ItemDefinition {
+                name: "Rifle Ammo",
+                kind: ItemKind::Material,
+                max_stack: 40,
+                icon: "DOUBLE_AMMO_SLOT.png",
+                pickup_sprite: "Zombie-Tileset---_0330_Capa-331.png",
+            },
+            ItemId::ShotgunShells => ItemDefinition {
+                name: "Shotgun Shells",
+                kind: ItemKind::Material,
+                max_stack: 32,
+                icon: "TRIPLE_AMMO_SLOT.png",
+                pickup_sprite: "Zombie-Tileset---_0343_Capa-344.png",
+            },
+            ItemId::GasCan => ItemDefinition {
+                name: "Gas Can",
+                kind: ItemKind::Material,
+                max_stack: 5,
+                icon: "GAS_CAN_SLOT.png",
+                pickup_sprite: "Zombie-Tileset---_0327_Capa-328.png",
+            },
+            ItemId::Radio => ItemDefinition {
+                name: "Radio",
+                kind: ItemKind::Key,
+                max_stack: 1,
+                icon: "RADIO_SLOT.png",
+                pickup_sprite: "Zombie-Tileset---_0328_Capa-329.png",
+            },
+        }
+    }
+
+    pub fn icon_path(&self) -> String {
+        inventory_sprite_path(self.definition().icon)
+    }
+
+    pub fn pickup_sprite_path(&self) -> String {
+        format!("{}/{}", PICKUP_SPRITES, self.definition().pickup_sprite)
+    }
+}
+
+pub fn inventory_sprite_path(file_name: &str) -> String {
+    format!("{}/{}", INVENTORY_SPRITES, file_name)
+}
@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use events::ItemUsedEvent;
+use systems::*;
+
+use crate::AppState;
+
+pub mod components;
+pub mod events;
+pub mod items;
+pub mod systems;
+
+pub struct InventoryPlugin;
+
+impl Plugin for InventoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<InventoryScreenState>();
+        app.add_event::<ItemUsedEvent>();
+        app.add_systems(
+            Update,
+            (
+                hotbar_selection_system,
+                use_selected_item_system,
+                toggle_inventory_screen_system,
+                pickup_world_items_system,
+            )
+                .run_if(in_state(AppState::InGame)),
+        );
+        app.add_systems(OnEnter(AppState::InGame), spawn_supply_cache_system);
+        app.add_systems(
+            OnExit(AppState::InGame),
+            (close_inventory_screen, despawn_world_items_system),
+        );
+    }
+}
+
+#[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum InventoryScreenState {
+    #[default]
+    Closed,
+    Open,
+}
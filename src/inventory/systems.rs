@@ -0,0 +1,171 @@
+use bevy::{input::mouse::AccumulatedMouseScroll, prelude::*};
+
+use crate::player::components::{Player, PlayerHealth};
+
+use super::{
+    components::{Inventory, ItemStack, WorldItem, HOTBAR_SIZE},
+    events::ItemUsedEvent,
+    items::{ConsumableEffect, ItemId, ItemKind},
+    InventoryScreenState,
+};
+
+const PICKUP_RADIUS: f32 = 24.0;
+/// Items waiting next to where the player starts.
+const SUPPLY_CACHE: [(ItemId, u32, Vec2); 3] = [
+    (ItemId::Shotgun, 1, Vec2::new(40.0, -24.0)),
+    (ItemId::ShotgunShells, 16, Vec2::new(52.0, -24.0)),
+    (ItemId::Grenade, 3, Vec2::new(64.0, -24.0)),
+];
+
+const HOTBAR_KEYS: [KeyCode; HOTBAR_SIZE] = [
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+];
+
+pub fn hotbar_selection_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_scroll: Res<AccumulatedMouseScroll>,
+    mut inventory_query: Query<&mut Inventory, With<Player>>,
+) {
+    let Ok(mut inventory) = inventory_query.get_single_mut() else {
+        return;
+    };
+
+    for (slot, key) in HOTBAR_KEYS.iter().enumerate() {
+        if keyboard_input.just_pressed(*key) {
+            inventory.select_hotbar_slot(slot);
+        }
+    }
+
+    // Scrolling while holding control zooms the camera instead.
+    if keyboard_input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+        return;
+    }
+    if mouse_scroll.delta.y > 0.0 {
+        inventory.cycle_hotbar(-1);
+    } else if mouse_scroll.delta.y < 0.0 {
+        inventory.cycle_hotbar(1);
+    }
+}
+
+pub fn use_selected_item_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut player_query: Query<(Entity, &mut Inventory, &mut PlayerHealth), With<Player>>,
+    mut item_used_events: EventWriter<ItemUsedEvent>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::KeyE) {
+        return;
+    }
+    let Ok((player, mut inventory, mut player_health)) = player_query.get_single_mut() else {
+        return;
+    };
+    let Some(stack) = inventory.selected_stack() else {
+        return;
+    };
+
+    let ItemKind::Consumable(effect) = stack.item.definition().kind else {
+        return;
+    };
+
+    match effect {
+        ConsumableEffect::Heal(amount) => {
+            if player_health.current_health() == player_health.max_health() {
+                return;
+            }
+            player_health.increase_health(amount);
+        }
+    }
+
+    let selected_slot = inventory.selected_slot();
+    inventory.remove_from_slot(selected_slot, 1);
+    item_used_events.send(ItemUsedEvent {
+        user: player,
+        item: stack.item,
+    });
+}
+
+pub fn toggle_inventory_screen_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    screen_state: Res<State<InventoryScreenState>>,
+    mut next_screen_state: ResMut<NextState<InventoryScreenState>>,
+) {
+    if !(keyboard_input.just_pressed(KeyCode::Tab) || keyboard_input.just_pressed(KeyCode::KeyI)) {
+        return;
+    }
+
+    next_screen_state.set(match screen_state.get() {
+        InventoryScreenState::Closed => InventoryScreenState::Open,
+        InventoryScreenState::Open => InventoryScreenState::Closed,
+    });
+}
+
+pub fn close_inventory_screen(mut next_screen_state: ResMut<NextState<InventoryScreenState>>) {
+    next_screen_state.set(InventoryScreenState::Closed);
+}
+
+pub fn spawn_supply_cache_system(mut commands: Commands, asset_server: Res<AssetServer>) {
+    for (item, count, position) in SUPPLY_CACHE {
+        spawn_world_item(
+            &mut commands,
+            &asset_server,
+            ItemStack::new(item, count),
+            position,
+        );
+    }
+}
+
+pub fn spawn_world_item(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    stack: ItemStack,
+    position: Vec2,
+) -> Entity {
+    commands
+        .spawn((
+            WorldItem(stack),
+            Sprite {
+                image: asset_server.load(stack.item.pickup_sprite_path()),
+                ..default()
+            },
+            Transform::from_translation(position.extend(5.0)),
+            Name::new(format!("WorldItem_{:?}", stack.item)),
+        ))
+        .id()
+}
+
+/// Moves items the player walks over into the inventory. What does not fit stays on the ground.
+pub fn pickup_world_items_system(
+    mut commands: Commands,
+    mut player_query: Query<(&Transform, &mut Inventory), With<Player>>,
+    mut item_query: Query<(Entity, &Transform, &mut WorldItem), Without<Player>>,
+) {
+    let Ok((player_transform, mut inventory)) = player_query.get_single_mut() else {
+        return;
+    };
+    let player_position = player_transform.translation.truncate();
+
+    for (entity, transform, mut world_item) in item_query.iter_mut() {
+        if transform.translation.truncate().distance(player_position) > PICKUP_RADIUS {
+            continue;
+        }
+        let leftover = inventory.add_item(world_item.0.item, world_item.0.count);
+        if leftover == 0 {
+            info!("Picked up {}", world_item.0.item.definition().name);
+            commands.entity(entity).despawn();
+        } else {
+            world_item.0.count = leftover;
+        }
+    }
+}
+
+pub fn despawn_world_items_system(
+    mut commands: Commands,
+    item_query: Query<Entity, With<WorldItem>>,
+) {
+    for entity in item_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
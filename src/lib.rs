@@ -4,9 +4,18 @@ use bevy::prelude::*;
 // write seperated unit test like things for our base behaviour and skip state stuff later on in
 // game development.
 pub mod audio;
+pub mod camera;
 pub mod enemy;
+pub mod explosives;
+pub mod hit_feedback;
+pub mod inventory;
+pub mod loot;
 pub mod map_genreation;
+pub mod navigation;
 pub mod player;
+pub mod pooling;
+pub mod sprites;
+pub mod status_effects;
 pub mod ui;
 pub mod collision;
 
@@ -14,6 +23,7 @@ pub mod collision;
 pub enum AppState {
     #[default]
     MainMenu,
+    CharacterSelect,
     InGame,
     GameOver,
     GameWon,
@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+/// Coins lying in the world, walking over them adds them to the player's `Coins`.
+#[derive(Component, Debug)]
+pub struct CoinPickup {
+    pub amount: u32,
+    pub frame: usize,
+    pub frame_timer: Timer,
+}
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+use resources::{Coins, LootRegistry};
+use systems::*;
+
+use crate::{enemy::systems::kill_enemy_system, AppState};
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+/// Drops from killed enemies, rolled from the loot tables of their archetypes.
+pub struct LootPlugin;
+
+impl Plugin for LootPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Coins>()
+            .insert_resource(LootRegistry::load())
+            .add_systems(Startup, load_loot_assets)
+            .add_systems(OnEnter(AppState::InGame), reset_coins_system)
+            .add_systems(
+                Update,
+                (
+                    drop_loot_system.after(kill_enemy_system),
+                    animate_coins_system,
+                    pickup_coins_system,
+                )
+                    .run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(OnExit(AppState::InGame), despawn_coins_system);
+    }
+}
@@ -0,0 +1,184 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use serde::Deserialize;
+
+use crate::inventory::{components::ItemStack, items::ItemId};
+
+/// Coins the player picked up during the run.
+#[derive(Resource, Debug, Default)]
+pub struct Coins(pub u32);
+
+#[derive(Resource)]
+pub struct LootAssets {
+    pub coin_frames: Vec<Handle<Image>>,
+}
+
+/// Something a loot table can drop, `count` is the (min, max) amount.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum LootDrop {
+    Item { item: ItemId, count: (u32, u32) },
+    Coins { count: (u32, u32) },
+}
+
+impl LootDrop {
+    fn roll(&self, rng: &mut impl Rng) -> Loot {
+        match *self {
+            LootDrop::Item {
+                item,
+                count: (min, max),
+            } => Loot::Item(ItemStack::new(item, rng.gen_range(min..=max.max(min)))),
+            LootDrop::Coins { count: (min, max) } => Loot::Coins(rng.gen_range(min..=max.max(min))),
+        }
+    }
+}
+
+/// A drop with its amount rolled, ready to be put into the world.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Loot {
+    Item(ItemStack),
+    Coins(u32),
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct WeightedDrop {
+    pub weight: u32,
+    pub drop: LootDrop,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LootTable {
+    pub id: String,
+    /// Dropped every time.
+    #[serde(default)]
+    pub guaranteed: Vec<LootDrop>,
+    /// Chance of every roll to drop one of the weighted drops, before luck.
+    #[serde(default)]
+    pub chance: f32,
+    #[serde(default = "default_rolls")]
+    pub rolls: u32,
+    #[serde(default)]
+    pub weighted: Vec<WeightedDrop>,
+}
+
+fn default_rolls() -> u32 {
+    1
+}
+
+impl LootTable {
+    /// Rolls the table once. `luck` scales the chance of the weighted drops, 0.5 makes them
+    /// 50% more likely.
+    pub fn roll(&self, luck: f32, rng: &mut impl Rng) -> Vec<Loot> {
+        let mut loot: Vec<Loot> = self.guaranteed.iter().map(|drop| drop.roll(rng)).collect();
+        let chance = (self.chance * (1.0 + luck)).clamp(0.0, 1.0);
+        for _ in 0..self.rolls {
+            if !rng.gen_bool(chance as f64) {
+                continue;
+            }
+            if let Ok(weighted) = self.weighted.choose_weighted(rng, |drop| drop.weight) {
+                loot.push(weighted.drop.roll(rng));
+            }
+        }
+        loot
+    }
+}
+
+/// Every loot table, loaded from `assets/data/loot.ron`.
+#[derive(Resource, Debug, Deserialize)]
+pub struct LootRegistry {
+    pub tables: Vec<LootTable>,
+}
+
+impl LootRegistry {
+    pub fn load() -> Self {
+        ron::from_str(include_str!("../../assets/data/loot.ron"))
+            .expect("assets/data/loot.ron should contain valid loot tables")
+    }
+
+    pub fn get(&self, id: &str) -> Option<&LootTable> {
+        self.tables.iter().find(|table| table.id == id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enemy::resources::EnemyRegistry;
+    use rand::rngs::StdRng;
+
+    fn table(chance: f32) -> LootTable {
+        LootTable {
+            id: "test".to_string(),
+            guaranteed: vec![LootDrop::Coins { count: (2, 4) }],
+            chance,
+            rolls: 1,
+            weighted: vec![
+                WeightedDrop {
+                    weight: 1,
+                    drop: LootDrop::Item {
+                        item: ItemId::Medicine,
+                        count: (1, 1),
+                    },
+                },
+                WeightedDrop {
+                    weight: 0,
+                    drop: LootDrop::Item {
+                        item: ItemId::Radio,
+                        count: (1, 1),
+                    },
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_loot_registry_covers_every_archetype() {
+        let loot = LootRegistry::load();
+        let enemies = EnemyRegistry::load();
+        for enemy in enemies.enemies.iter() {
+            let table_id = enemy.loot_table.as_ref().unwrap();
+            assert!(
+                loot.get(table_id).is_some(),
+                "unknown loot table {}",
+                table_id
+            );
+        }
+        for table in loot.tables.iter() {
+            assert!((0.0..=1.0).contains(&table.chance));
+            assert!(table.chance == 0.0 || table.weighted.iter().any(|drop| drop.weight > 0));
+        }
+    }
+
+    #[test]
+    fn test_guaranteed_drops_always_come() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let table = table(0.0);
+        for _ in 0..50 {
+            let loot = table.roll(0.0, &mut rng);
+            assert_eq!(loot.len(), 1);
+            assert!(matches!(loot[0], Loot::Coins(2..=4)));
+        }
+    }
+
+    #[test]
+    fn test_luck_raises_the_drop_chance() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let table = table(0.25);
+        let drops = |luck: f32, rng: &mut StdRng| {
+            (0..2000)
+                .map(|_| table.roll(luck, rng).len() - 1)
+                .sum::<usize>()
+        };
+
+        let unlucky = drops(0.0, &mut rng);
+        let lucky = drops(1.0, &mut rng);
+        assert!((400..600).contains(&unlucky), "{} drops", unlucky);
+        assert!((900..1100).contains(&lucky), "{} drops", lucky);
+
+        // Drops without weight never come.
+        for _ in 0..100 {
+            for loot in table.roll(10.0, &mut rng) {
+                assert_ne!(loot, Loot::Item(ItemStack::new(ItemId::Radio, 1)));
+            }
+        }
+    }
+}
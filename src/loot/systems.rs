@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use std::f32::consts::TAU;
+
+use crate::{
+    enemy::{events::EnemyKilledEvent, resources::EnemyRegistry},
+    inventory::systems::spawn_world_item,
+    player::components::{Player, PlayerLuck},
+    sprites::frame_path,
+};
+
+use super::{
+    components::CoinPickup,
+    resources::{Coins, Loot, LootAssets, LootRegistry},
+};
+
+const COIN_FOLDER: &str = "Spawning Money Animation Frames";
+const COIN_FRAMES: std::ops::RangeInclusive<u32> = 350..=354;
+const COIN_FRAME_SECONDS: f32 = 0.08;
+const COIN_SCALE: f32 = 1.5;
+const COIN_Z: f32 = 5.0;
+const PICKUP_RADIUS: f32 = 24.0;
+/// Drops land up to this far from where the enemy died, so they don't all stack up.
+const DROP_SCATTER: f32 = 14.0;
+
+pub fn load_loot_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(LootAssets {
+        coin_frames: COIN_FRAMES
+            .map(|frame| asset_server.load(frame_path(COIN_FOLDER, frame)))
+            .collect(),
+    });
+}
+
+pub fn reset_coins_system(mut coins: ResMut<Coins>) {
+    coins.0 = 0;
+}
+
+/// Rolls the loot table of every killed enemy and scatters the drops where it died.
+pub fn drop_loot_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    assets: Res<LootAssets>,
+    enemy_registry: Res<EnemyRegistry>,
+    loot_registry: Res<LootRegistry>,
+    player_query: Query<&PlayerLuck, With<Player>>,
+    mut killed_events: EventReader<EnemyKilledEvent>,
+) {
+    let luck = player_query.get_single().map_or(0.0, |luck| luck.0);
+    let mut rng = thread_rng();
+
+    for event in killed_events.read() {
+        let Some(table_id) = enemy_registry
+            .get(&event.archetype)
+            .and_then(|archetype| archetype.loot_table.as_ref())
+        else {
+            continue;
+        };
+        let Some(table) = loot_registry.get(table_id) else {
+            warn!(
+                "{} drops an unknown loot table {}",
+                event.archetype, table_id
+            );
+            continue;
+        };
+
+        for loot in table.roll(luck, &mut rng) {
+            let position = event.position
+                + Vec2::from_angle(rng.gen_range(0.0..TAU)) * rng.gen_range(0.0..DROP_SCATTER);
+            match loot {
+                Loot::Item(stack) => {
+                    spawn_world_item(&mut commands, &asset_server, stack, position);
+                }
+                Loot::Coins(amount) => {
+                    commands.spawn((
+                        CoinPickup {
+                            amount,
+                            frame: 0,
+                            frame_timer: Timer::from_seconds(
+                                COIN_FRAME_SECONDS,
+                                TimerMode::Repeating,
+                            ),
+                        },
+                        Sprite {
+                            image: assets.coin_frames.first().cloned().unwrap_or_default(),
+                            ..default()
+                        },
+                        Transform::from_translation(position.extend(COIN_Z))
+                            .with_scale(Vec3::splat(COIN_SCALE)),
+                        Name::new("CoinPickup"),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Plays the spawning animation of dropped coins once and keeps them on its last frame.
+pub fn animate_coins_system(
+    time: Res<Time>,
+    assets: Res<LootAssets>,
+    mut coin_query: Query<(&mut CoinPickup, &mut Sprite)>,
+) {
+    let last_frame = assets.coin_frames.len().saturating_sub(1);
+    for (mut coin, mut sprite) in coin_query.iter_mut() {
+        if coin.frame >= last_frame {
+            continue;
+        }
+        if coin.frame_timer.tick(time.delta()).just_finished() {
+            coin.frame += 1;
+            sprite.image = assets.coin_frames[coin.frame].clone();
+        }
+    }
+}
+
+pub fn pickup_coins_system(
+    mut commands: Commands,
+    mut coins: ResMut<Coins>,
+    player_query: Query<&Transform, With<Player>>,
+    coin_query: Query<(Entity, &Transform, &CoinPickup), Without<Player>>,
+) {
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+    let player_position = player_transform.translation.truncate();
+
+    for (entity, transform, coin) in coin_query.iter() {
+        if transform.translation.truncate().distance(player_position) > PICKUP_RADIUS {
+            continue;
+        }
+        coins.0 += coin.amount;
+        commands.entity(entity).despawn();
+    }
+}
+
+pub fn despawn_coins_system(mut commands: Commands, coin_query: Query<Entity, With<CoinPickup>>) {
+    for entity in coin_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -1,18 +1,34 @@
 use avian2d::prelude::*;
 use bevy::prelude::*;
+use camera::CameraPlugin;
 use enemy::EnemyPlugin;
+use explosives::ExplosivesPlugin;
+use hit_feedback::HitFeedbackPlugin;
+use inventory::InventoryPlugin;
+use loot::LootPlugin;
 use fuzzy_broccoli::{audio::AudioPlugin, AppState};
 use main_menu::MainMenuPlugin;
+use navigation::NavigationPlugin;
 use player::PlayerPlugin;
+use status_effects::StatusEffectsPlugin;
 use ui::UiPlugin;
 use crate::map_genreation::TerrainPlugin;
 
 mod main_menu;
 
 mod audio;
+mod camera;
 mod enemy;
+mod explosives;
+mod hit_feedback;
+mod inventory;
+mod loot;
 mod map_genreation;
+mod navigation;
 pub mod player;
+mod pooling;
+mod sprites;
+mod status_effects;
 mod ui;
 pub mod collision;
 
@@ -43,8 +59,15 @@ fn main() {
     app.add_plugins((
         MainMenuPlugin,
         PlayerPlugin,
+        CameraPlugin,
         EnemyPlugin,
+        ExplosivesPlugin,
+        StatusEffectsPlugin,
+        HitFeedbackPlugin,
+        InventoryPlugin,
+        LootPlugin,
         TerrainPlugin,
+        NavigationPlugin,
         AudioPlugin,
         UiPlugin,
     ));
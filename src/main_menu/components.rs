@@ -8,3 +8,18 @@ pub struct PlayButton {}
 
 #[derive(Component)]
 pub struct QuitButton {}
+
+#[derive(Component)]
+pub struct ReducedMotionButton {}
+
+#[derive(Component)]
+pub struct CharacterSelectMenu {}
+
+#[derive(Component)]
+pub struct CharacterButton {
+    pub character_id: String,
+    pub unlocked: bool,
+}
+
+#[derive(Component)]
+pub struct BackButton {}
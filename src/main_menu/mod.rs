@@ -1,6 +1,9 @@
 use bevy::prelude::*;
 use fuzzy_broccoli::AppState;
-use systems::interactions::{interact_with_play_button, interact_with_quit_button};
+use systems::interactions::{
+    interact_with_back_button, interact_with_character_button, interact_with_play_button,
+    interact_with_quit_button, interact_with_reduced_motion_button,
+};
 
 pub mod components;
 pub mod systems;
@@ -15,8 +18,19 @@ impl Plugin for MainMenuPlugin {
             .add_systems(OnExit(AppState::MainMenu), despawn_main_menu)
             .add_systems(
                 Update,
-                (interact_with_play_button, interact_with_quit_button)
+                (
+                    interact_with_play_button,
+                    interact_with_reduced_motion_button,
+                    interact_with_quit_button,
+                )
                     .run_if(in_state(AppState::MainMenu)),
+            )
+            .add_systems(OnEnter(AppState::CharacterSelect), spawn_character_select)
+            .add_systems(OnExit(AppState::CharacterSelect), despawn_character_select)
+            .add_systems(
+                Update,
+                (interact_with_character_button, interact_with_back_button)
+                    .run_if(in_state(AppState::CharacterSelect)),
             );
     }
 }
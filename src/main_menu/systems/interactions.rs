@@ -1,9 +1,12 @@
 use bevy::prelude::*;
 
+use crate::camera::resources::AccessibilitySettings;
 use crate::main_menu::{
-    components::{PlayButton, QuitButton},
+    components::{BackButton, CharacterButton, PlayButton, QuitButton, ReducedMotionButton},
+    systems::layouts::reduced_motion_label,
     AppState,
 };
+use crate::player::character::resources::SelectedCharacter;
 
 pub fn interact_with_play_button(
     mut button_query: Query<&Interaction, (Changed<Interaction>, With<PlayButton>)>,
@@ -11,7 +14,7 @@ pub fn interact_with_play_button(
 ) {
     if let Ok(interaction) = button_query.get_single_mut() {
         match *interaction {
-            Interaction::Pressed => next_app_state.set(AppState::InGame),
+            Interaction::Pressed => next_app_state.set(AppState::CharacterSelect),
             Interaction::Hovered => {}
             Interaction::None => {}
         }
@@ -32,3 +35,43 @@ pub fn interact_with_quit_button(
         }
     }
 }
+
+pub fn interact_with_reduced_motion_button(
+    button_query: Query<(&Interaction, &Children), (Changed<Interaction>, With<ReducedMotionButton>)>,
+    mut text_query: Query<&mut Text>,
+    mut accessibility_settings: ResMut<AccessibilitySettings>,
+) {
+    for (interaction, children) in button_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        accessibility_settings.reduced_motion = !accessibility_settings.reduced_motion;
+        for child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(*child) {
+                **text = reduced_motion_label(accessibility_settings.reduced_motion);
+            }
+        }
+    }
+}
+
+pub fn interact_with_character_button(
+    button_query: Query<(&Interaction, &CharacterButton), Changed<Interaction>>,
+    mut selected_character: ResMut<SelectedCharacter>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, character_button) in button_query.iter() {
+        if *interaction == Interaction::Pressed && character_button.unlocked {
+            selected_character.0 = character_button.character_id.clone();
+            next_app_state.set(AppState::InGame);
+        }
+    }
+}
+
+pub fn interact_with_back_button(
+    button_query: Query<&Interaction, (Changed<Interaction>, With<BackButton>)>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok(Interaction::Pressed) = button_query.get_single() {
+        next_app_state.set(AppState::MainMenu);
+    }
+}
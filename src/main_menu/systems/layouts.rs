@@ -1,9 +1,27 @@
 use bevy::prelude::*;
 
-use crate::main_menu::components::{MainMenu, PlayButton, QuitButton};
+use crate::camera::resources::AccessibilitySettings;
+use crate::main_menu::components::{
+    BackButton, CharacterButton, CharacterSelectMenu, MainMenu, PlayButton, QuitButton,
+    ReducedMotionButton,
+};
+use crate::player::character::resources::{CharacterRoster, UnlockedCharacters};
 
-pub fn spawn_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let _main_menu_entity = build_main_menu(&mut commands, &asset_server);
+pub fn spawn_main_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    accessibility_settings: Res<AccessibilitySettings>,
+) {
+    let _main_menu_entity =
+        build_main_menu(&mut commands, &asset_server, accessibility_settings.reduced_motion);
+}
+
+pub fn reduced_motion_label(reduced_motion: bool) -> String {
+    if reduced_motion {
+        "Reduced Motion: On".to_string()
+    } else {
+        "Reduced Motion: Off".to_string()
+    }
 }
 
 pub fn despawn_main_menu(mut commands: Commands, main_menu_query: Query<Entity, With<MainMenu>>) {
@@ -13,7 +31,11 @@ pub fn despawn_main_menu(mut commands: Commands, main_menu_query: Query<Entity,
     }
 }
 
-pub fn build_main_menu(commands: &mut Commands, asset_server: &Res<AssetServer>) -> Entity {
+pub fn build_main_menu(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    reduced_motion: bool,
+) -> Entity {
     let main_menu_entity = commands
         .spawn((
             Node {
@@ -97,6 +119,30 @@ pub fn build_main_menu(commands: &mut Commands, asset_server: &Res<AssetServer>)
                     ));
                 });
 
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(50.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.5, 0.8, 0.8)),
+                    Button {},
+                    ReducedMotionButton {},
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text(reduced_motion_label(reduced_motion)),
+                        TextFont {
+                            font_size: 20.0,
+                            ..default()
+                        },
+                    ));
+                });
+
             parent
                 .spawn((
                     Node {
@@ -124,3 +170,122 @@ pub fn build_main_menu(commands: &mut Commands, asset_server: &Res<AssetServer>)
         .id();
     main_menu_entity
 }
+
+pub fn spawn_character_select(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    roster: Res<CharacterRoster>,
+    unlocked_characters: Res<UnlockedCharacters>,
+) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            ImageNode {
+                image: asset_server.load("zombiebackground.png"),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.5, 0.5, 0.5)),
+            CharacterSelectMenu {},
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text("Choose your Survivor".to_string()),
+                TextFont {
+                    font_size: 38.0,
+                    ..default()
+                },
+            ));
+
+            for character in roster.characters.iter() {
+                let unlocked = unlocked_characters.is_unlocked(character);
+                let details = match character.unlock {
+                    Some(requirement) if !unlocked => {
+                        format!("Locked: {}", requirement.description())
+                    }
+                    _ => format!(
+                        "HP {} | Speed {} | {}",
+                        character.max_health, character.movement_speed, character.description
+                    ),
+                };
+                let background_color = if unlocked {
+                    Color::srgb(0.5, 0.8, 0.8)
+                } else {
+                    Color::srgb(0.3, 0.3, 0.3)
+                };
+
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Px(420.0),
+                            height: Val::Px(70.0),
+                            flex_direction: FlexDirection::Column,
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(background_color),
+                        Button {},
+                        CharacterButton {
+                            character_id: character.id.clone(),
+                            unlocked,
+                        },
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((
+                            Text(character.name.clone()),
+                            TextFont {
+                                font_size: 28.0,
+                                ..default()
+                            },
+                        ));
+                        parent.spawn((
+                            Text(details),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                        ));
+                    });
+            }
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(50.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.5, 0.8, 0.8)),
+                    Button {},
+                    BackButton {},
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text("Back".to_string()),
+                        TextFont {
+                            font_size: 32.0,
+                            ..default()
+                        },
+                    ));
+                });
+        });
+}
+
+pub fn despawn_character_select(
+    mut commands: Commands,
+    character_select_query: Query<Entity, With<CharacterSelectMenu>>,
+) {
+    if let Ok(character_select_entity) = character_select_query.get_single() {
+        commands.entity(character_select_entity).despawn_recursive();
+    }
+}
@@ -2,6 +2,10 @@ use bevy::prelude::{Component, Event};
 
 #[derive(Component)]
 pub struct TileComponent;
+
+/// Tiles like trees, rocks and buildings that stop projectiles.
+#[derive(Component)]
+pub struct ObstacleTile;
 #[derive(Event)]
 pub struct ResetTerrainEvent;
 
@@ -1,6 +1,11 @@
 pub const TILE_W: usize = 16;
 pub const TILE_H: usize = 16;
 pub const SPRITE_SCALE_FACTOR: f32 = 3.0;
+/// Tiles drawn on this layer or above are obstacles like trees, rocks and buildings.
+pub const OBSTACLE_Z_INDEX: i32 = 3;
+/// Chance of an explosive barrel standing on a free ground tile.
+pub const BARREL_CHANCE: f64 = 0.0015;
+pub const BARREL_Z_INDEX: i32 = 4;
 pub const SPRITE_SHEET_PATH: &str = "zombie_apocalypse_tileset/tileset.png";
 
 pub const GRID_COLS: usize = 1000;
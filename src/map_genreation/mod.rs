@@ -1,5 +1,5 @@
 use crate::map_genreation::components::ResetTerrainEvent;
-use crate::map_genreation::resources::{CurrentChunks, GenerationSeed, GroundTiles};
+use crate::map_genreation::resources::{CurrentChunks, GenerationSeed, GroundTiles, ObstacleTiles};
 use crate::map_genreation::systems::{
     clean_ground_tiles, despawn_chunks, handle_player_chunk_update_event,
     handle_terrain_reset_event,
@@ -12,7 +12,7 @@ use bevy::utils::{HashMap, HashSet};
 use rand::Rng;
 use std::time::Duration;
 
-mod components;
+pub mod components;
 mod config;
 pub mod resources;
 mod systems;
@@ -25,6 +25,7 @@ impl Plugin for TerrainPlugin {
         let mut rng = rand::thread_rng();
         app.add_event::<PlayerChunkUpdateEvent>();
         app.insert_resource(GroundTiles(HashSet::new()))
+            .insert_resource(ObstacleTiles(HashSet::new()))
             .insert_resource(CurrentChunks(HashMap::new()))
             .insert_resource(GenerationSeed(rng.gen()))
             .add_systems(Update, handle_terrain_reset_event)
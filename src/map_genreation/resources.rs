@@ -3,6 +3,9 @@ use bevy::utils::{HashMap, HashSet};
 
 #[derive(Resource)]
 pub struct GroundTiles(pub HashSet<(i32, i32)>);
+/// Grid positions of the loaded obstacle tiles, enemies path around them.
+#[derive(Resource)]
+pub struct ObstacleTiles(pub HashSet<(i32, i32)>);
 #[derive(Resource)]
 pub struct CurrentChunks(pub HashMap<(i32, i32), Vec<Entity>>);
 #[derive(Resource)]
@@ -1,8 +1,13 @@
-use crate::map_genreation::components::{ResetTerrainEvent, Tile, TileComponent};
-use crate::map_genreation::config::{CHUNK_H, CHUNK_W, SPRITE_SCALE_FACTOR, SPRITE_SHEET_PATH};
-use crate::map_genreation::resources::{CurrentChunks, GenerationSeed, GroundTiles};
+use crate::collision::GameLayer;
+use crate::explosives::components::ExplosiveBarrel;
+use crate::map_genreation::components::{ObstacleTile, ResetTerrainEvent, Tile, TileComponent};
+use crate::map_genreation::config::{
+    BARREL_CHANCE, BARREL_Z_INDEX, CHUNK_H, CHUNK_W, OBSTACLE_Z_INDEX, SPRITE_SCALE_FACTOR, SPRITE_SHEET_PATH, TILE_H, TILE_W,
+};
+use crate::map_genreation::resources::{CurrentChunks, GenerationSeed, GroundTiles, ObstacleTiles};
 use crate::map_genreation::util::{center_to_top_left, grid_to_chunk, grid_to_world};
 use crate::player::components::{CurrentPlayerChunkPos, PlayerChunkUpdateEvent};
+use avian2d::prelude::{Collider, CollisionLayers, RigidBody};
 use bevy::asset::{AssetServer, Assets};
 use bevy::math::{UVec2, Vec3};
 use bevy::prelude::{Commands, Entity, EventReader, EventWriter, Query, Res, ResMut, Sprite, TextureAtlas, TextureAtlasLayout, Transform, With};
@@ -19,6 +24,7 @@ pub fn handle_terrain_reset_event(
     player_pos: Res<CurrentPlayerChunkPos>,
     mut chunks: ResMut<CurrentChunks>,
     mut ground_tiles: ResMut<GroundTiles>,
+    mut obstacle_tiles: ResMut<ObstacleTiles>,
     mut seed: ResMut<GenerationSeed>,
     tile_q: Query<Entity, With<TileComponent>>,
 ) {
@@ -33,6 +39,7 @@ pub fn handle_terrain_reset_event(
 
     chunks.0.clear();
     ground_tiles.0.clear();
+    obstacle_tiles.0.clear();
 
     let mut rng = rand::thread_rng();
     seed.0 = rng.gen();
@@ -55,6 +62,7 @@ pub fn clean_ground_tiles(
 pub fn despawn_chunks(
     mut commands: Commands,
     mut current_chunks: ResMut<CurrentChunks>,
+    mut obstacle_tiles: ResMut<ObstacleTiles>,
     player_pos: Res<CurrentPlayerChunkPos>,
 ) {
     let mut keys_to_remove = Vec::new();
@@ -65,17 +73,27 @@ pub fn despawn_chunks(
             continue;
         }
 
+        // Barrels may have blown up already.
         for e in entities.iter() {
-            commands.entity(*e).despawn();
+            if let Some(mut entity_commands) = commands.get_entity(*e) {
+                entity_commands.despawn();
+            }
         }
         keys_to_remove.push((*cx, *cy));
     }
 
+    if keys_to_remove.is_empty() {
+        return;
+    }
+    obstacle_tiles
+        .0
+        .retain(|pos| !keys_to_remove.contains(&grid_to_chunk(pos.0 as f32, pos.1 as f32)));
     for (cx, cy) in keys_to_remove {
         current_chunks.0.remove(&(cx, cy));
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handle_player_chunk_update_event(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
@@ -84,6 +102,7 @@ pub fn handle_player_chunk_update_event(
     mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
     mut ev_chunk_update: EventReader<PlayerChunkUpdateEvent>,
     mut ground_tiles: ResMut<GroundTiles>,
+    mut obstacle_tiles: ResMut<ObstacleTiles>,
 ) {
     if ev_chunk_update.is_empty() {
         return;
@@ -115,6 +134,7 @@ pub fn handle_player_chunk_update_event(
         ];
         let mut tiles = HashSet::new();
         let mut ground_map = HashSet::new();
+        let mut barrels = HashSet::new();
 
         for (i, j) in chunk_nei {
             let (x, y) = (x + i, y + j);
@@ -123,9 +143,11 @@ pub fn handle_player_chunk_update_event(
             }
 
             let start = (x * CHUNK_W as i32, y * CHUNK_H as i32);
-            let (chunk_tiles, chunk_ground_map) = gen_chunk(seed.0, (start.0, start.1));
+            let (chunk_tiles, chunk_ground_map, chunk_barrels) =
+                gen_chunk(seed.0, (start.0, start.1));
             tiles.extend(chunk_tiles);
             ground_map.extend(chunk_ground_map);
+            barrels.extend(chunk_barrels);
         }
 
         let mut updated_ground_map = HashSet::new();
@@ -160,7 +182,38 @@ pub fn handle_player_chunk_update_event(
                     TileComponent,
                 ))
                 .id();
+            if t.z_index >= OBSTACLE_Z_INDEX {
+                // Obstacles only block projectiles, enemies path around them.
+                obstacle_tiles.0.insert(t.pos);
+                commands.entity(e).insert((
+                    ObstacleTile,
+                    RigidBody::Static,
+                    Collider::rectangle(TILE_W as f32, TILE_H as f32),
+                    CollisionLayers::new(
+                        GameLayer::TERRAIN,
+                        [GameLayer::PROJECTILE, GameLayer::ENEMY_PROJECTILE],
+                    ),
+                ));
+            }
+
+            current_chunks
+                .0
+                .entry((cx, cy))
+                .or_insert_with(Vec::new)
+                .push(e);
+        }
 
+        for (x, y) in barrels {
+            let (cx, cy) = grid_to_chunk(x as f32, y as f32);
+            let (x, y) = grid_to_world(x as f32, y as f32);
+            let (x, y) = center_to_top_left(x, y);
+            let e = commands
+                .spawn((
+                    ExplosiveBarrel,
+                    Transform::from_xyz(x, y, BARREL_Z_INDEX as f32),
+                    TileComponent,
+                ))
+                .id();
             current_chunks
                 .0
                 .entry((cx, cy))
@@ -170,12 +223,18 @@ pub fn handle_player_chunk_update_event(
     }
 }
 
-pub fn gen_chunk(gen_seed: u32, start: (i32, i32)) -> (HashSet<Tile>, HashSet<(i32, i32)>) {
+/// Returns the tiles of the chunk, the ground positions and where barrels stand.
+#[allow(clippy::type_complexity)]
+pub fn gen_chunk(
+    gen_seed: u32,
+    start: (i32, i32),
+) -> (HashSet<Tile>, HashSet<(i32, i32)>, HashSet<(i32, i32)>) {
     let mut rng = rand::thread_rng();
     let noise = Perlin::new(gen_seed);
 
     let mut tiles = HashSet::new();
     let mut ground_map = HashSet::new();
+    let mut barrels = HashSet::new();
     let end = (start.0 + CHUNK_W as i32, start.1 + CHUNK_H as i32);
 
     for x in start.0 - 1..end.0 + 1 {
@@ -283,11 +342,18 @@ pub fn gen_chunk(gen_seed: u32, start: (i32, i32)) -> (HashSet<Tile>, HashSet<(i
                     let tile = rng.gen_range(41..=44);
                     tiles.insert(Tile::new((x, y), tile, 5));
                 }
+                continue;
+            }
+
+            // The border belongs to the neighbouring chunks, barrels there would be doubled.
+            let inside_chunk = (start.0..end.0).contains(&x) && (start.1..end.1).contains(&y);
+            if inside_chunk && rng.gen_bool(BARREL_CHANCE) {
+                barrels.insert((x, y));
             }
         }
     }
 
-    (tiles, ground_map)
+    (tiles, ground_map, barrels)
 }
 pub fn process_tile((x, y): (i32, i32), occupied: &HashSet<(i32, i32)>) -> (i32, usize) {
     let nei_options = [(-1, 0), (1, 0), (0, -1), (0, 1)];
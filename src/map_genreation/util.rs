@@ -30,3 +30,36 @@ pub fn grid_to_chunk(x: f32, y: f32) -> (i32, i32) {
     let (x, y) = (x / CHUNK_W as f32, y / CHUNK_H as f32);
     (x.floor() as i32, y.floor() as i32)
 }
+
+/// Grid position of the tile at a world position.
+pub fn world_to_tile(x: f32, y: f32) -> (i32, i32) {
+    let (x, y) = world_to_grid(x, y);
+    let (x, y) = center_to_top_left_grid(x, y);
+    (x.round() as i32, y.round() as i32)
+}
+
+/// Chunk containing a world position, the same way the player's chunk is worked out.
+pub fn world_to_chunk(x: f32, y: f32) -> (i32, i32) {
+    let (x, y) = world_to_grid(x, y);
+    let (x, y) = center_to_top_left_grid(x, y);
+    grid_to_chunk(x, y)
+}
+
+/// World position of a tile's center.
+pub fn tile_to_world((x, y): (i32, i32)) -> (f32, f32) {
+    let (x, y) = grid_to_world(x as f32, y as f32);
+    center_to_top_left(x, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tile_world_round_trip() {
+        for tile in [(0, 0), (500, 400), (523, 377), (999, 799)] {
+            let (x, y) = tile_to_world(tile);
+            assert_eq!(world_to_tile(x, y), tile);
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use resources::FlowField;
+use systems::*;
+
+use crate::AppState;
+
+pub mod resources;
+mod systems;
+
+/// Shared flow field towards the player that enemies follow around water and obstacles.
+pub struct NavigationPlugin;
+
+impl Plugin for NavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FlowField>().add_systems(
+            Update,
+            update_flow_field_system.run_if(in_state(AppState::InGame)),
+        );
+    }
+}
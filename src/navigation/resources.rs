@@ -0,0 +1,202 @@
+use bevy::prelude::*;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::map_genreation::util::world_to_tile;
+
+/// Tiles around the player the flow field covers in every direction. Enemies further away walk
+/// straight towards the player until they are in range.
+pub const FLOW_FIELD_RADIUS: i32 = 32;
+
+const STRAIGHT_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+const UNREACHABLE: u32 = u32::MAX;
+
+const NEIGHBOURS: [(i32, i32); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+
+/// Walking distance from every tile around the player to the player, shared by all enemies so
+/// each of them only has to look at its neighbouring tiles to find the way.
+#[derive(Resource, Debug, Default)]
+pub struct FlowField {
+    /// Tile the player stands on, `None` until the field is built.
+    origin: Option<(i32, i32)>,
+    radius: i32,
+    /// Distances in row-major order, `UNREACHABLE` for tiles that cannot be walked to.
+    distances: Vec<u32>,
+}
+
+impl FlowField {
+    /// Runs Dijkstra outwards from `origin` over the tiles `walkable` allows. Diagonal steps may
+    /// not cut the corner of an unwalkable tile. The origin itself is always reachable.
+    pub fn build(origin: (i32, i32), radius: i32, walkable: impl Fn((i32, i32)) -> bool) -> Self {
+        let side = (radius * 2 + 1) as usize;
+        let mut field = Self {
+            origin: Some(origin),
+            radius,
+            distances: vec![UNREACHABLE; side * side],
+        };
+        let is_walkable = |tile: (i32, i32)| tile == origin || walkable(tile);
+
+        let mut queue = BinaryHeap::new();
+        if let Some(index) = field.index(origin) {
+            field.distances[index] = 0;
+            queue.push(Reverse((0, origin)));
+        }
+
+        while let Some(Reverse((distance, tile))) = queue.pop() {
+            if field.distance(tile).is_some_and(|best| best < distance) {
+                continue;
+            }
+            for (dx, dy) in NEIGHBOURS {
+                let next = (tile.0 + dx, tile.1 + dy);
+                let Some(index) = field.index(next) else {
+                    continue;
+                };
+                if !is_walkable(next) {
+                    continue;
+                }
+                let diagonal = dx != 0 && dy != 0;
+                if diagonal
+                    && !(is_walkable((tile.0 + dx, tile.1)) && is_walkable((tile.0, tile.1 + dy)))
+                {
+                    continue;
+                }
+                let cost = if diagonal {
+                    DIAGONAL_COST
+                } else {
+                    STRAIGHT_COST
+                };
+                let next_distance = distance + cost;
+                if next_distance < field.distances[index] {
+                    field.distances[index] = next_distance;
+                    queue.push(Reverse((next_distance, next)));
+                }
+            }
+        }
+        field
+    }
+
+    pub fn origin(&self) -> Option<(i32, i32)> {
+        self.origin
+    }
+
+    /// Walking distance from `tile` to the origin, `None` if it is unreachable or out of range.
+    pub fn distance(&self, tile: (i32, i32)) -> Option<u32> {
+        self.index(tile)
+            .map(|index| self.distances[index])
+            .filter(|distance| *distance != UNREACHABLE)
+    }
+
+    /// Direction to walk from a world position to get closer to the player. `None` on the
+    /// player's own tile and where the field has no path, callers then head straight for the
+    /// player.
+    pub fn direction(&self, position: Vec2) -> Option<Vec2> {
+        let tile = world_to_tile(position.x, position.y);
+        let distance = self.distance(tile)?;
+        if distance == 0 {
+            return None;
+        }
+
+        let (dx, dy) = NEIGHBOURS
+            .iter()
+            .copied()
+            .filter_map(|(dx, dy)| {
+                let next = self.distance((tile.0 + dx, tile.1 + dy))?;
+                // Like the field itself, diagonal steps don't cut corners.
+                let diagonal = dx != 0 && dy != 0;
+                let corner_free = !diagonal
+                    || (self.distance((tile.0 + dx, tile.1)).is_some()
+                        && self.distance((tile.0, tile.1 + dy)).is_some());
+                (next < distance && corner_free).then_some((next, (dx, dy)))
+            })
+            .min_by_key(|(next, _)| *next)?
+            .1;
+        // Grid rows grow downwards while world y grows upwards.
+        Some(Vec2::new(dx as f32, -dy as f32).normalize())
+    }
+
+    fn index(&self, (x, y): (i32, i32)) -> Option<usize> {
+        let (origin_x, origin_y) = self.origin?;
+        let (x, y) = (x - origin_x + self.radius, y - origin_y + self.radius);
+        let side = self.radius * 2 + 1;
+        if x < 0 || y < 0 || x >= side || y >= side {
+            return None;
+        }
+        Some((y * side + x) as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map_genreation::util::tile_to_world;
+
+    fn world(tile: (i32, i32)) -> Vec2 {
+        let (x, y) = tile_to_world(tile);
+        Vec2::new(x, y)
+    }
+
+    #[test]
+    fn test_path_leads_around_a_wall() {
+        // A vertical wall at x = 5 from y = 0 to y = 8, with the way around below it.
+        let wall = |(x, y): (i32, i32)| x == 5 && (0..=8).contains(&y);
+        let field = FlowField::build((10, 5), 8, |tile| !wall(tile));
+
+        assert_eq!(field.distance((10, 5)), Some(0));
+        assert!(field.distance((5, 5)).is_none());
+        // Straight through the wall would be 60, around it takes much longer.
+        assert!(field.distance((4, 5)).unwrap() > 60);
+
+        // Right in front of the wall the way leads down towards the gap.
+        let direction = field.direction(world((4, 5))).unwrap();
+        assert!(
+            direction.y < 0.0,
+            "{:?} does not lead around the wall",
+            direction
+        );
+
+        // Following the field reaches the player.
+        let mut tile = (2, 2);
+        for _ in 0..40 {
+            let Some(direction) = field.direction(world(tile)) else {
+                break;
+            };
+            tile = (
+                tile.0 + direction.x.round() as i32,
+                tile.1 - direction.y.round() as i32,
+            );
+            assert!(!wall(tile));
+        }
+        assert_eq!(tile, (10, 5));
+    }
+
+    #[test]
+    fn test_unreachable_and_distant_tiles_have_no_direction() {
+        // (3, 0) is walled in on every side.
+        let walled_in = |(x, y): (i32, i32)| (x - 3).abs().max(y.abs()) == 1;
+        let field = FlowField::build((0, 0), 4, |tile| !walled_in(tile));
+
+        assert!(field.direction(world((0, 0))).is_none());
+        assert!(field.direction(world((0, 3))).is_some());
+        assert!(field.direction(world((3, 0))).is_none());
+        assert!(field.direction(world((5, 0))).is_none());
+        assert!(FlowField::default().direction(world((0, 0))).is_none());
+    }
+
+    #[test]
+    fn test_diagonals_do_not_cut_corners() {
+        let field = FlowField::build((0, 0), 2, |tile| tile != (1, 0));
+        // (1, 1) could step diagonally onto the origin, but (1, 0) blocks the corner.
+        assert_eq!(field.distance((1, 1)), Some(20));
+        assert_eq!(field.direction(world((1, 1))), Some(Vec2::new(-1.0, 0.0)));
+    }
+}
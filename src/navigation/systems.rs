@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+use crate::{
+    map_genreation::{
+        resources::{GroundTiles, ObstacleTiles},
+        util::world_to_tile,
+    },
+    player::components::Player,
+};
+
+use super::resources::{FlowField, FLOW_FIELD_RADIUS};
+
+/// Rebuilds the flow field when the player enters another tile or chunks stream in and out.
+pub fn update_flow_field_system(
+    mut flow_field: ResMut<FlowField>,
+    ground_tiles: Res<GroundTiles>,
+    obstacle_tiles: Res<ObstacleTiles>,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+    let player_tile = world_to_tile(
+        player_transform.translation.x,
+        player_transform.translation.y,
+    );
+
+    let map_changed = ground_tiles.is_changed() || obstacle_tiles.is_changed();
+    if !map_changed && flow_field.origin() == Some(player_tile) {
+        return;
+    }
+
+    *flow_field = FlowField::build(player_tile, FLOW_FIELD_RADIUS, |tile| {
+        ground_tiles.0.contains(&tile) && !obstacle_tiles.0.contains(&tile)
+    });
+}
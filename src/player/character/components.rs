@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::player::components::DamageInvulnerability;
+
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct PlayableCharacter(pub String);
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum PassiveAbility {
+    None,
+    Regeneration { interval_seconds: f32 },
+    ThickSkin { invulnerability_seconds: f32 },
+}
+
+impl PassiveAbility {
+    /// Invulnerability granted after the player took damage.
+    pub fn damage_invulnerability(passive: Option<&Self>) -> DamageInvulnerability {
+        match passive {
+            Some(PassiveAbility::ThickSkin {
+                invulnerability_seconds,
+            }) => DamageInvulnerability::from_seconds(*invulnerability_seconds),
+            _ => DamageInvulnerability::default(),
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct RegenerationTimer(pub Timer);
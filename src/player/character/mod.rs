@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+use resources::{CharacterRoster, SelectedCharacter};
+use systems::*;
+
+use crate::AppState;
+
+pub mod components;
+pub mod resources;
+pub mod systems;
+
+pub struct CharacterPlugin;
+
+impl Plugin for CharacterPlugin {
+    fn build(&self, app: &mut App) {
+        let roster = CharacterRoster::load();
+        app.insert_resource(SelectedCharacter(roster.default_character().id.clone()));
+        app.insert_resource(roster);
+        app.add_systems(Startup, load_unlocked_characters_system);
+        app.add_systems(OnEnter(AppState::InGame), apply_selected_character_system);
+        app.add_systems(
+            Update,
+            regeneration_passive_system.run_if(in_state(AppState::InGame)),
+        );
+        app.add_systems(
+            OnEnter(AppState::GameOver),
+            unlock_characters_on_game_over_system,
+        );
+        app.add_systems(
+            OnEnter(AppState::GameWon),
+            unlock_characters_on_game_won_system,
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::inventory::items::ItemId;
+use crate::player::resources::PlayerFacingDirection;
+use crate::sprites::frame_path;
+
+use super::components::PassiveAbility;
+
+pub const UNLOCKS_SAVE_PATH: &str = "save/unlocked_characters.ron";
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CharacterAnimationSet {
+    pub folder: String,
+    pub down: u32,
+    pub side: u32,
+    pub up: u32,
+    pub side_faces: PlayerFacingDirection,
+}
+
+impl CharacterAnimationSet {
+    pub fn first_frame(&self, direction: PlayerFacingDirection) -> u32 {
+        match direction {
+            PlayerFacingDirection::Down => self.down,
+            PlayerFacingDirection::Up => self.up,
+            PlayerFacingDirection::Left | PlayerFacingDirection::Right => self.side,
+        }
+    }
+
+    pub fn frame_path(&self, frame: u32) -> String {
+        frame_path(&self.folder, frame)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum UnlockRequirement {
+    KillsInRun(u32),
+    SurviveRun,
+}
+
+impl UnlockRequirement {
+    pub fn is_met(&self, kills: u32, survived: bool) -> bool {
+        match self {
+            UnlockRequirement::KillsInRun(required_kills) => kills >= *required_kills,
+            UnlockRequirement::SurviveRun => survived,
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            UnlockRequirement::KillsInRun(kills) => format!("Kill {} enemies in one run", kills),
+            UnlockRequirement::SurviveRun => "Survive a whole run".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CharacterDefinition {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub max_health: u8,
+    pub movement_speed: f32,
+    /// See `PlayerLuck`.
+    #[serde(default)]
+    pub luck: f32,
+    pub starting_weapon: ItemId,
+    #[serde(default)]
+    pub starting_items: Vec<(ItemId, u32)>,
+    pub passive: PassiveAbility,
+    pub animation: CharacterAnimationSet,
+    /// Frames shown while the character flashes after being hit.
+    #[serde(default)]
+    pub damaged_animation: Option<CharacterAnimationSet>,
+    pub sprite_size: f32,
+    pub unlock: Option<UnlockRequirement>,
+}
+
+#[derive(Resource, Debug, Deserialize)]
+pub struct CharacterRoster {
+    pub characters: Vec<CharacterDefinition>,
+}
+
+impl CharacterRoster {
+    pub fn load() -> Self {
+        ron::from_str(include_str!("../../../assets/data/characters.ron"))
+            .expect("assets/data/characters.ron should contain a valid character roster")
+    }
+
+    pub fn get(&self, id: &str) -> Option<&CharacterDefinition> {
+        self.characters.iter().find(|character| character.id == id)
+    }
+
+    pub fn default_character(&self) -> &CharacterDefinition {
+        self.characters
+            .iter()
+            .find(|character| character.unlock.is_none())
+            .expect("At least one character has to be unlocked from the start")
+    }
+}
+
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct SelectedCharacter(pub String);
+
+#[derive(Resource, Debug, Default, Serialize, Deserialize)]
+pub struct UnlockedCharacters(pub HashSet<String>);
+
+impl UnlockedCharacters {
+    pub fn is_unlocked(&self, character: &CharacterDefinition) -> bool {
+        character.unlock.is_none() || self.0.contains(&character.id)
+    }
+
+    /// Unlocks every character whose requirement was met during the run and
+    /// returns the ids of the newly unlocked ones.
+    pub fn unlock_from_run(
+        &mut self,
+        roster: &CharacterRoster,
+        kills: u32,
+        survived: bool,
+    ) -> Vec<String> {
+        let mut newly_unlocked = Vec::new();
+        for character in roster.characters.iter() {
+            let Some(requirement) = character.unlock else {
+                continue;
+            };
+            if !self.0.contains(&character.id) && requirement.is_met(kills, survived) {
+                self.0.insert(character.id.clone());
+                newly_unlocked.push(character.id.clone());
+            }
+        }
+        newly_unlocked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roster_data_is_valid() {
+        let roster = CharacterRoster::load();
+        assert!(!roster.characters.is_empty());
+        assert!(roster.default_character().unlock.is_none());
+        for character in roster.characters.iter() {
+            assert!(character.max_health > 0, "{} needs health", character.id);
+        }
+    }
+
+    #[test]
+    fn test_unlock_from_run() {
+        let roster = CharacterRoster::load();
+        let mut unlocked = UnlockedCharacters::default();
+
+        let newly_unlocked = unlocked.unlock_from_run(&roster, 0, false);
+        assert!(newly_unlocked.is_empty(), "Nothing is earned without kills");
+
+        let newly_unlocked = unlocked.unlock_from_run(&roster, 100, false);
+        assert_eq!(newly_unlocked, vec!["infected".to_string()]);
+
+        let newly_unlocked = unlocked.unlock_from_run(&roster, 100, true);
+        assert_eq!(
+            newly_unlocked,
+            vec!["brute".to_string()],
+            "Already unlocked characters are not reported again"
+        );
+    }
+}
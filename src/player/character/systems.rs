@@ -0,0 +1,142 @@
+use bevy::prelude::*;
+use std::fs;
+
+use crate::{
+    enemy::resources::EnemyKillCount,
+    inventory::components::Inventory,
+    player::{
+        components::{Player, PlayerHealth, PlayerLuck, PlayerMovementSpeed},
+        weapon::components::WeaponMagazines,
+    },
+};
+
+use super::{
+    components::{PassiveAbility, PlayableCharacter, RegenerationTimer},
+    resources::{
+        CharacterDefinition, CharacterRoster, SelectedCharacter, UnlockedCharacters,
+        UNLOCKS_SAVE_PATH,
+    },
+};
+
+pub fn character_components(
+    character: &CharacterDefinition,
+) -> (
+    PlayableCharacter,
+    PlayerHealth,
+    PlayerMovementSpeed,
+    PlayerLuck,
+    PassiveAbility,
+    Inventory,
+    WeaponMagazines,
+) {
+    let mut inventory = Inventory::with_items(&[(character.starting_weapon, 1)]);
+    for (item, count) in character.starting_items.iter() {
+        inventory.add_item(*item, *count);
+    }
+
+    (
+        PlayableCharacter(character.id.clone()),
+        PlayerHealth::new(character.max_health),
+        PlayerMovementSpeed(character.movement_speed),
+        PlayerLuck(character.luck),
+        character.passive,
+        inventory,
+        WeaponMagazines::default(),
+    )
+}
+
+pub fn apply_selected_character_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    roster: Res<CharacterRoster>,
+    selected_character: Res<SelectedCharacter>,
+    mut player_query: Query<(Entity, &mut Sprite), With<Player>>,
+) {
+    let Ok((player, mut sprite)) = player_query.get_single_mut() else {
+        return;
+    };
+    let Some(character) = roster.get(&selected_character.0) else {
+        warn!("Selected character {} is not in the roster", selected_character.0);
+        return;
+    };
+
+    let animation = &character.animation;
+    sprite.image = asset_server.load(animation.frame_path(animation.down));
+    sprite.custom_size = Some(Vec2::splat(character.sprite_size));
+    let mut player_commands = commands.entity(player);
+    player_commands.insert(character_components(character));
+    match character.passive {
+        PassiveAbility::Regeneration { interval_seconds } => {
+            player_commands.insert(RegenerationTimer(Timer::from_seconds(
+                interval_seconds,
+                TimerMode::Repeating,
+            )));
+        }
+        _ => {
+            player_commands.remove::<RegenerationTimer>();
+        }
+    }
+    info!("Playing as {}", character.name);
+}
+
+pub fn regeneration_passive_system(
+    time: Res<Time>,
+    mut player_query: Query<(&mut RegenerationTimer, &mut PlayerHealth), With<Player>>,
+) {
+    for (mut timer, mut player_health) in player_query.iter_mut() {
+        if timer.0.tick(time.delta()).just_finished()
+            && player_health.current_health() < player_health.max_health()
+        {
+            player_health.increase_health(1);
+        }
+    }
+}
+
+pub fn load_unlocked_characters_system(mut commands: Commands) {
+    let unlocked_characters = fs::read_to_string(UNLOCKS_SAVE_PATH)
+        .ok()
+        .and_then(|content| ron::from_str::<UnlockedCharacters>(&content).ok())
+        .unwrap_or_default();
+    commands.insert_resource(unlocked_characters);
+}
+
+fn unlock_characters(
+    roster: &CharacterRoster,
+    unlocked_characters: &mut UnlockedCharacters,
+    kills: u32,
+    survived: bool,
+) {
+    let newly_unlocked = unlocked_characters.unlock_from_run(roster, kills, survived);
+    if newly_unlocked.is_empty() {
+        return;
+    }
+    info!("Unlocked characters: {:?}", newly_unlocked);
+
+    let saved = ron::to_string(&*unlocked_characters)
+        .map_err(|error| error.to_string())
+        .and_then(|content| {
+            if let Some(save_dir) = std::path::Path::new(UNLOCKS_SAVE_PATH).parent() {
+                fs::create_dir_all(save_dir).map_err(|error| error.to_string())?;
+            }
+            fs::write(UNLOCKS_SAVE_PATH, content).map_err(|error| error.to_string())
+        });
+    if let Err(error) = saved {
+        warn!("Could not save unlocked characters: {}", error);
+    }
+}
+
+pub fn unlock_characters_on_game_over_system(
+    roster: Res<CharacterRoster>,
+    kill_count: Res<EnemyKillCount>,
+    mut unlocked_characters: ResMut<UnlockedCharacters>,
+) {
+    unlock_characters(&roster, &mut unlocked_characters, kill_count.0, false);
+}
+
+pub fn unlock_characters_on_game_won_system(
+    roster: Res<CharacterRoster>,
+    kill_count: Res<EnemyKillCount>,
+    mut unlocked_characters: ResMut<UnlockedCharacters>,
+) {
+    unlock_characters(&roster, &mut unlocked_characters, kill_count.0, true);
+}
@@ -1,8 +1,16 @@
 use bevy::prelude::*;
 
+use crate::status_effects::components::{PendingTickDamage, StatusEffects};
+
 #[derive(Component)]
+#[require(StatusEffects, PendingTickDamage, LastGroundPosition)]
 pub struct Player;
 
+/// Where the player last stood on walkable ground, they are put back there when they end up
+/// in the water.
+#[derive(Component, Debug, Default)]
+pub struct LastGroundPosition(pub Option<Vec2>);
+
 #[derive(Component)]
 #[require(Camera2d)]
 pub struct PlayerCamera;
@@ -15,6 +23,20 @@ pub struct AnimationFrame(pub u8);
 #[derive(Component)]
 pub struct PlayerMovementSpeed(pub f32);
 
+/// Raises the chance of enemies dropping loot, 0.5 makes chance drops 50% more likely.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct PlayerLuck(pub f32);
+
+/// Normalized direction from the player towards the cursor.
+#[derive(Component)]
+pub struct PlayerAim(pub Vec2);
+
+impl Default for PlayerAim {
+    fn default() -> Self {
+        Self(Vec2::NEG_Y)
+    }
+}
+
 #[derive(Component)]
 pub struct PlayerAnimationTimer(pub Timer);
 
@@ -95,8 +117,14 @@ pub struct DamageInvulnerability {
 
 impl Default for DamageInvulnerability {
     fn default() -> Self {
+        Self::from_seconds(1.0)
+    }
+}
+
+impl DamageInvulnerability {
+    pub fn from_seconds(duration: f32) -> Self {
         Self {
-            timer: Timer::from_seconds(1.0, TimerMode::Once)
+            timer: Timer::from_seconds(duration, TimerMode::Once)
         }
     }
 }
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeleePhase {
+    Startup,
+    Active,
+    Recovery,
+}
+
+/// Present on the player while a knife swing is in progress.
+#[derive(Component, Debug)]
+pub struct MeleeAttack {
+    pub phase: MeleePhase,
+    /// Runs out at the end of the current phase.
+    pub timer: Timer,
+    /// Entity drawing the swing.
+    pub swing: Entity,
+    /// Child of the swing that exists during the active window.
+    pub hitbox: Option<Entity>,
+    /// Enemies already hit by this swing.
+    pub hits: Vec<Entity>,
+}
+
+/// The slash drawn in front of the player, its frames are spread over the whole swing.
+#[derive(Component)]
+pub struct MeleeSwing {
+    pub timer: Timer,
+}
+
+/// Arc shaped sensor in front of the player during the active window of a swing.
+#[derive(Component)]
+pub struct MeleeHitbox {
+    pub owner: Entity,
+}
@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+use resources::MeleeSettings;
+use systems::{
+    load_melee_assets, melee_hit_system, melee_phase_system, melee_swing_animation_system,
+    start_melee_system,
+};
+
+use crate::{inventory::InventoryScreenState, AppState};
+
+use super::player_aim_system;
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+/// Knife attack on the right mouse button or F, usable with any weapon and without ammo.
+pub struct MeleePlugin;
+
+impl Plugin for MeleePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(MeleeSettings::load());
+        app.add_systems(Startup, load_melee_assets);
+        app.add_systems(
+            Update,
+            (
+                start_melee_system.run_if(in_state(InventoryScreenState::Closed)),
+                melee_phase_system,
+                melee_swing_animation_system,
+                melee_hit_system,
+            )
+                .chain()
+                .after(player_aim_system)
+                .run_if(in_state(AppState::InGame)),
+        );
+    }
+}
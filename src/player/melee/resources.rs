@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::sprites::SPRITE_FOLDER;
+
+#[derive(Resource, Debug, Deserialize)]
+pub struct MeleeSettings {
+    pub damage: f32,
+    /// Multiplier on how far hit enemies are pushed back.
+    pub knockback: f32,
+    /// Radius of the arc in front of the player.
+    pub reach: f32,
+    /// Full angle of the arc, centered on the aim direction.
+    pub arc_degrees: f32,
+    /// Wind up before the blade can hit anything.
+    pub startup_seconds: f32,
+    /// Window in which enemies inside the arc are hit.
+    pub active_seconds: f32,
+    /// Cool down after the hit before the next swing.
+    pub recovery_seconds: f32,
+    /// Sprite paths relative to organized_separated_sprites.
+    pub animation: Vec<String>,
+}
+
+impl MeleeSettings {
+    pub fn load() -> Self {
+        ron::from_str(include_str!("../../../assets/data/knife.ron"))
+            .expect("assets/data/knife.ron should contain valid melee settings")
+    }
+
+    pub fn swing_seconds(&self) -> f32 {
+        self.startup_seconds + self.active_seconds + self.recovery_seconds
+    }
+
+    pub fn animation_paths(&self) -> impl Iterator<Item = String> + '_ {
+        self.animation
+            .iter()
+            .map(|frame| format!("{}/{}", SPRITE_FOLDER, frame))
+    }
+
+    /// Outline of the arc pointing along +X: the player's center followed by points on the
+    /// rim. Its convex hull is the hitbox, so arcs wider than 180 degrees are not supported.
+    pub fn arc_outline(&self) -> Vec<Vec2> {
+        const RIM_SEGMENTS: usize = 8;
+        let arc = self.arc_degrees.to_radians().min(std::f32::consts::PI);
+        let rim = (0..=RIM_SEGMENTS).map(|segment| {
+            let angle = -arc * 0.5 + arc * segment as f32 / RIM_SEGMENTS as f32;
+            Vec2::from_angle(angle) * self.reach
+        });
+        std::iter::once(Vec2::ZERO).chain(rim).collect()
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct MeleeAssets {
+    pub frames: Vec<Handle<Image>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_melee_settings_load() {
+        let settings = MeleeSettings::load();
+
+        assert!(settings.damage > 0.0);
+        assert!(settings.active_seconds > 0.0, "the knife would never hit");
+        assert!(!settings.animation.is_empty());
+    }
+
+    #[test]
+    fn test_arc_outline_stays_within_reach() {
+        let settings = MeleeSettings {
+            reach: 20.0,
+            arc_degrees: 90.0,
+            ..MeleeSettings::load()
+        };
+
+        let outline = settings.arc_outline();
+
+        assert_eq!(outline[0], Vec2::ZERO);
+        assert!(outline[1..]
+            .iter()
+            .all(|point| (point.length() - 20.0).abs() < 1e-3 && point.x > 0.0));
+        assert!((outline[1].to_angle() + std::f32::consts::FRAC_PI_4).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,182 @@
+use avian2d::prelude::*;
+use bevy::{prelude::*, sprite::Anchor};
+
+use crate::{
+    collision::GameLayer,
+    enemy::components::{Enemy, EnemyHealth},
+    hit_feedback::events::{DamageEvent, DamageSource, HitEvent},
+    player::components::{Player, PlayerAim},
+};
+
+use super::{
+    components::{MeleeAttack, MeleeHitbox, MeleePhase, MeleeSwing},
+    resources::{MeleeAssets, MeleeSettings},
+};
+
+pub fn load_melee_assets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<MeleeSettings>,
+) {
+    commands.insert_resource(MeleeAssets {
+        frames: settings
+            .animation_paths()
+            .map(|path| asset_server.load(path))
+            .collect(),
+    });
+}
+
+pub fn start_melee_system(
+    mut commands: Commands,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    key_input: Res<ButtonInput<KeyCode>>,
+    settings: Res<MeleeSettings>,
+    melee_assets: Res<MeleeAssets>,
+    player_query: Query<(Entity, &PlayerAim), (With<Player>, Without<MeleeAttack>)>,
+) {
+    if !(mouse_input.just_pressed(MouseButton::Right) || key_input.just_pressed(KeyCode::KeyF)) {
+        return;
+    }
+    let Ok((player, aim)) = player_query.get_single() else {
+        return;
+    };
+
+    let swing = commands
+        .spawn((
+            MeleeSwing {
+                timer: Timer::from_seconds(settings.swing_seconds(), TimerMode::Once),
+            },
+            Sprite {
+                image: melee_assets.frames.first().cloned().unwrap_or_default(),
+                anchor: Anchor::CenterLeft,
+                flip_y: aim.0.x < 0.0,
+                ..default()
+            },
+            Transform::from_xyz(0.0, 0.0, 1.5)
+                .with_rotation(Quat::from_rotation_z(aim.0.to_angle())),
+            Name::new("MeleeSwing"),
+        ))
+        .id();
+    commands
+        .entity(player)
+        .add_child(swing)
+        .insert(MeleeAttack {
+            phase: MeleePhase::Startup,
+            timer: Timer::from_seconds(settings.startup_seconds, TimerMode::Once),
+            swing,
+            hitbox: None,
+            hits: Vec::new(),
+        });
+}
+
+/// Moves swings through their startup, active and recovery windows. The hitbox only exists
+/// during the active window.
+pub fn melee_phase_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<MeleeSettings>,
+    mut player_query: Query<(Entity, &mut MeleeAttack)>,
+) {
+    for (player, mut attack) in player_query.iter_mut() {
+        if !attack.timer.tick(time.delta()).finished() {
+            continue;
+        }
+
+        match attack.phase {
+            MeleePhase::Startup => {
+                attack.phase = MeleePhase::Active;
+                attack.timer = Timer::from_seconds(settings.active_seconds, TimerMode::Once);
+                let collider = Collider::convex_hull(settings.arc_outline())
+                    .expect("the knife arc should have a convex outline");
+                let hitbox = commands
+                    .spawn((
+                        MeleeHitbox { owner: player },
+                        collider,
+                        Sensor,
+                        CollisionLayers::new(GameLayer::MELEE, [GameLayer::ENEMY]),
+                        Transform::default(),
+                    ))
+                    .id();
+                commands.entity(attack.swing).add_child(hitbox);
+                attack.hitbox = Some(hitbox);
+            }
+            MeleePhase::Active => {
+                attack.phase = MeleePhase::Recovery;
+                attack.timer = Timer::from_seconds(settings.recovery_seconds, TimerMode::Once);
+                if let Some(hitbox) = attack.hitbox.take() {
+                    commands.entity(hitbox).despawn();
+                }
+            }
+            MeleePhase::Recovery => {
+                commands.entity(attack.swing).despawn_recursive();
+                commands.entity(player).remove::<MeleeAttack>();
+            }
+        }
+    }
+}
+
+pub fn melee_swing_animation_system(
+    time: Res<Time>,
+    melee_assets: Res<MeleeAssets>,
+    mut swing_query: Query<(&mut MeleeSwing, &mut Sprite)>,
+) {
+    let Some(last_frame) = melee_assets.frames.len().checked_sub(1) else {
+        return;
+    };
+
+    for (mut swing, mut sprite) in swing_query.iter_mut() {
+        swing.timer.tick(time.delta());
+        let frame = (swing.timer.fraction() * melee_assets.frames.len() as f32) as usize;
+        sprite.image = melee_assets.frames[frame.min(last_frame)].clone();
+    }
+}
+
+/// Damages every enemy inside the arc once per swing. The hitbox is a sensor, so the
+/// overlap is reported every frame and `MeleeAttack::hits` filters the repeats.
+pub fn melee_hit_system(
+    settings: Res<MeleeSettings>,
+    hitbox_query: Query<&MeleeHitbox>,
+    mut attack_query: Query<(&mut MeleeAttack, &GlobalTransform)>,
+    mut enemy_query: Query<(&mut EnemyHealth, &GlobalTransform), With<Enemy>>,
+    mut collision_events: EventReader<Collision>,
+    mut hit_events: EventWriter<HitEvent>,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    for collision in collision_events.read() {
+        for (hitbox_entity, other_entity) in [
+            (collision.0.entity1, collision.0.entity2),
+            (collision.0.entity2, collision.0.entity1),
+        ] {
+            let Ok(hitbox) = hitbox_query.get(hitbox_entity) else {
+                continue;
+            };
+            let Ok((mut attack, owner_transform)) = attack_query.get_mut(hitbox.owner) else {
+                continue;
+            };
+            let Ok((mut enemy_health, enemy_transform)) = enemy_query.get_mut(other_entity) else {
+                continue;
+            };
+            if attack.phase != MeleePhase::Active || attack.hits.contains(&other_entity) {
+                continue;
+            }
+
+            attack.hits.push(other_entity);
+            enemy_health.0 -= settings.damage;
+            damage_events.send(DamageEvent {
+                target: other_entity,
+                amount: settings.damage,
+                source: DamageSource::Melee,
+                dealer: Some(hitbox.owner),
+                position: enemy_transform.translation().truncate(),
+                critical: false,
+                lethal: enemy_health.0 <= 0.0,
+            });
+            hit_events.send(HitEvent {
+                target: other_entity,
+                direction: (enemy_transform.translation() - owner_transform.translation())
+                    .truncate(),
+                force: settings.knockback,
+            });
+        }
+    }
+}
@@ -1,22 +1,27 @@
 use bevy::prelude::*;
-use pistol::PistolPlugin;
+use character::CharacterPlugin;
+use melee::MeleePlugin;
+use weapon::WeaponPlugin;
 use resources::WorldMouseCoordinates;
 use systems::*;
 use crate::player::components::CurrentPlayerChunkPos;
 
+use crate::inventory::InventoryScreenState;
 use crate::AppState;
 
+pub mod character;
 pub mod components;
+pub mod melee;
 mod resources;
 mod systems;
 
-pub mod pistol;
+pub mod weapon;
 
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(PistolPlugin);
+        app.add_plugins((CharacterPlugin, WeaponPlugin, MeleePlugin));
         app.init_state::<PlayerState>();
         app.insert_resource(CurrentPlayerChunkPos::default());
         app.add_systems(
@@ -25,24 +30,27 @@ impl Plugin for PlayerPlugin {
         );
         app.add_systems(
             Update,
-            (player_movement_system, player_ground_collision_system, move_camera).chain(),
+            (player_movement_system, player_ground_collision_system).chain(),
+        );
+        app.add_systems(Update, cursor_system);
+        app.add_systems(
+            Update,
+            (player_aim_system, player_movement_animation_system)
+                .chain()
+                .after(cursor_system),
         );
-        app.add_systems(Update, (zoom_control_system, cursor_system));
         app.add_systems(
             Update,
             (
-                player_animation_tick_system,
-                fire_weapon_system,
+                player_animation_tick_system.run_if(in_state(PlayerState::Moving)),
+                fire_weapon_system.run_if(in_state(InventoryScreenState::Closed)),
+                release_trigger_system.run_if(in_state(InventoryScreenState::Open)),
                 player_enemy_collision_damage_system,
             )
                 .run_if(in_state(AppState::InGame)),
         );
 
         app.add_systems(Update, update_player_chunk_pos);
-        app.add_systems(
-            Update,
-            player_movement_animation_system.run_if(in_state(PlayerState::Moving)),
-        );
         app.add_systems(
             OnEnter(PlayerState::Idle),
             set_player_animation_to_start_frame,
@@ -1,10 +0,0 @@
-use bevy::prelude::Component;
-
-#[derive(Component)]
-pub struct Pistol;
-
-#[derive(Component)]
-pub struct Damage(pub f32);
-
-#[derive(Component)]
-pub struct Projectile;
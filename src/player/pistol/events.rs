@@ -1,4 +0,0 @@
-use bevy::prelude::*;
-
-#[derive(Event)]
-pub struct WeaponFiredEvent(pub Vec2);
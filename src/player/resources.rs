@@ -1,10 +1,19 @@
 use bevy::prelude::*;
+use serde::Deserialize;
 use std::collections::HashMap;
 
+pub struct CharacterAnimationFrames {
+    pub frames: HashMap<PlayerFacingDirection, Vec<Handle<Image>>>,
+    /// Empty if the character has no damaged animation.
+    pub damaged_frames: HashMap<PlayerFacingDirection, Vec<Handle<Image>>>,
+    pub flipped_direction: PlayerFacingDirection,
+}
+
+/// Walking animation frames per playable character id.
 #[derive(Resource)]
-pub struct PlayerAnimationFrames(pub HashMap<PlayerFacingDirection, Vec<Handle<Image>>>);
+pub struct PlayerAnimationFrames(pub HashMap<String, CharacterAnimationFrames>);
 
-#[derive(Component, PartialEq, Eq, Clone, Copy, Hash)]
+#[derive(Component, PartialEq, Eq, Clone, Copy, Hash, Debug, Deserialize)]
 pub enum PlayerFacingDirection {
     Up,
     Down,
@@ -12,5 +21,21 @@ pub enum PlayerFacingDirection {
     Right,
 }
 
+impl PlayerFacingDirection {
+    pub fn from_direction(direction: Vec2) -> Self {
+        if direction.x.abs() > direction.y.abs() {
+            if direction.x > 0.0 {
+                PlayerFacingDirection::Right
+            } else {
+                PlayerFacingDirection::Left
+            }
+        } else if direction.y > 0.0 {
+            PlayerFacingDirection::Up
+        } else {
+            PlayerFacingDirection::Down
+        }
+    }
+}
+
 #[derive(Resource, Default)]
 pub struct WorldMouseCoordinates(pub Vec2);
@@ -6,35 +6,68 @@ use crate::map_genreation::util::{center_to_top_left_grid, grid_to_chunk, world_
 use crate::map_genreation::resources::GroundTiles;
 
 use crate::{
+    camera::components::CameraRig,
     collision::GameLayer,
-    enemy::components::Enemy,
+    enemy::components::{ContactDamage, Enemy},
+    hit_feedback::{
+        components::{HitFlash, HitReaction, Knockback},
+        events::HitEvent,
+    },
+    status_effects::components::StatusEffects,
 };
 
 use super::{
+    character::{
+        components::{PassiveAbility, PlayableCharacter},
+        resources::{CharacterAnimationSet, CharacterRoster, SelectedCharacter},
+        systems::character_components,
+    },
     components::*,
-    pistol::events::WeaponFiredEvent,
-    resources::{PlayerAnimationFrames, PlayerFacingDirection, WorldMouseCoordinates},
+    weapon::resources::WeaponTrigger,
+    resources::{
+        CharacterAnimationFrames, PlayerAnimationFrames, PlayerFacingDirection,
+        WorldMouseCoordinates,
+    },
     PlayerState,
 };
 
-pub fn spawn_player(mut commands: Commands, asset_server: Res<AssetServer>) {
+pub fn spawn_player(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    roster: Res<CharacterRoster>,
+    selected_character: Res<SelectedCharacter>,
+) {
+    let character = roster
+        .get(&selected_character.0)
+        .unwrap_or_else(|| roster.default_character());
+    let animation = &character.animation;
+
     commands.spawn((
         Player,
         Sprite{
-            image: asset_server.load("zombie_apocalypse_tileset/organized_separated_sprites/Player Character Walking Animation Frames/Zombie-Tileset---_0476_Capa-477.png"),
-            custom_size: Some(Vec2::new(32.0,32.0)),
+            image: asset_server.load(animation.frame_path(animation.down)),
+            custom_size: Some(Vec2::splat(character.sprite_size)),
             ..default()
         },
         Transform::from_xyz(0.0, 0.0, 9.0),
-        PlayerMovementSpeed(100.0),
-        PlayerHealth::new(3),
+        character_components(character),
         RigidBody::Kinematic,
         Collider::round_rectangle(17.0, 20.0, 4.0),
         Mass(10.0),
         PlayerAnimationTimer(Timer::from_seconds(0.2, TimerMode::Repeating)),
         PlayerAnimationFrame(0),
         PlayerFacingDirection::Down,
-        CollisionLayers::new(GameLayer::PLAYER, [GameLayer::ENEMY, GameLayer::PLAYER]),
+        PlayerAim::default(),
+        HitReaction::player(),
+        CollisionLayers::new(
+            GameLayer::PLAYER,
+            [
+                GameLayer::ENEMY,
+                GameLayer::PLAYER,
+                GameLayer::ENEMY_PROJECTILE,
+                GameLayer::TERRAIN,
+            ],
+        ),
     ));
 }
 
@@ -45,6 +78,7 @@ pub fn spawn_player_camera(
     let player_position = player_location_query.get_single().unwrap();
     commands.spawn((
         PlayerCamera,
+        CameraRig::new(player_position.translation.truncate()),
         Transform::from_xyz(
             player_position.translation.x,
             player_position.translation.y,
@@ -59,24 +93,21 @@ pub fn spawn_player_camera(
 }
 
 pub fn player_ground_collision_system(
-    mut player_query: Query<(&mut Transform, &PlayerFacingDirection), With<Player>>,
+    mut player_query: Query<(&mut Transform, &mut LastGroundPosition), With<Player>>,
     ground_tiles: Res<GroundTiles>,
 ) {
-    if let Ok((mut transform, facing_direction)) = player_query.get_single_mut() {
+    if let Ok((mut transform, mut last_ground_position)) = player_query.get_single_mut() {
         let current_pos = transform.translation;
 
         let (grid_x, grid_y) = world_to_grid(current_pos.x, current_pos.y);
         let (grid_x, grid_y) = center_to_top_left_grid(grid_x, grid_y);
         let grid_coords = (grid_x.round() as i32, grid_y.round() as i32);
 
-        if !ground_tiles.0.contains(&grid_coords) {
-            let offset = match facing_direction {
-                PlayerFacingDirection::Left => Vec3::new(16.0, 0.0, 0.0),
-                PlayerFacingDirection::Right => Vec3::new(-16.0, 0.0, 0.0),
-                PlayerFacingDirection::Up => Vec3::new(0.0, -16.0, 0.0),
-                PlayerFacingDirection::Down => Vec3::new(0.0, 16.0, 0.0),
-            };
-            transform.translation += offset;
+        if ground_tiles.0.contains(&grid_coords) {
+            last_ground_position.0 = Some(current_pos.truncate());
+        } else if let Some(position) = last_ground_position.0 {
+            // Works even without velocity, like when knockback ends over the water.
+            transform.translation = position.extend(current_pos.z);
         }
     }
 }
@@ -86,34 +117,38 @@ pub fn player_movement_system(
     mut player_query: Query<
         (
             &mut LinearVelocity,
-            &mut PlayerFacingDirection,
             &PlayerMovementSpeed,
+            &StatusEffects,
+            Option<&Knockback>,
         ),
         With<Player>,
     >,
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mut next_app_state: ResMut<NextState<PlayerState>>,
 ) {
-    if let Ok((mut velocity, mut facing_direction, movement_speed)) = player_query.get_single_mut()
+    if let Ok((mut velocity, movement_speed, status_effects, knockback)) =
+        player_query.get_single_mut()
     {
+        if let Some(knockback) = knockback {
+            velocity.0 = knockback.current_velocity();
+            return;
+        }
+
         let mut direction: Vec2 = Vec2::ZERO;
         if keyboard_input.pressed(KeyCode::ArrowLeft) || keyboard_input.pressed(KeyCode::KeyA) {
             direction.x -= 1.0;
-            *facing_direction = PlayerFacingDirection::Left;
         }
         if keyboard_input.pressed(KeyCode::ArrowRight) || keyboard_input.pressed(KeyCode::KeyD) {
             direction.x += 1.0;
-            *facing_direction = PlayerFacingDirection::Right;
         }
         if keyboard_input.pressed(KeyCode::ArrowUp) || keyboard_input.pressed(KeyCode::KeyW) {
             direction.y += 1.0;
-            *facing_direction = PlayerFacingDirection::Up;
         }
         if keyboard_input.pressed(KeyCode::ArrowDown) || keyboard_input.pressed(KeyCode::KeyS) {
             direction.y -= 1.0;
-            *facing_direction = PlayerFacingDirection::Down;
         }
-        let move_delta = direction.normalize_or_zero() * movement_speed.0;
+        let move_delta =
+            direction.normalize_or_zero() * movement_speed.0 * status_effects.speed_multiplier();
 
         velocity.x = move_delta.x;
         velocity.y = move_delta.y;
@@ -151,42 +186,6 @@ pub fn update_player_chunk_pos(
 }
 
 
-pub fn move_camera(
-    time: Res<Time>,
-    mut player_camera_query: Query<&mut Transform, (With<PlayerCamera>, Without<Player>)>,
-    player_location_query: Query<&Transform, With<Player>>,
-) {
-    let Ok(mut camera) = player_camera_query.get_single_mut() else {
-        return;
-    };
-
-    let Ok(player) = player_location_query.get_single() else {
-        return;
-    };
-
-    let Vec3 { x, y, .. } = player.translation;
-    let direction = Vec3::new(x, y, camera.translation.z);
-
-    camera.translation = camera.translation.lerp(direction, time.delta_secs() * 4.0);
-}
-
-pub fn zoom_control_system(
-    input: Res<ButtonInput<KeyCode>>,
-    mut camera_query: Query<&mut OrthographicProjection, With<PlayerCamera>>,
-) {
-    let mut projection = camera_query.single_mut();
-
-    if input.pressed(KeyCode::Digit0) {
-        projection.scale += 0.05;
-    }
-
-    if input.pressed(KeyCode::Digit9) {
-        projection.scale -= 0.05;
-    }
-
-    projection.scale = projection.scale.clamp(0.2, 5.);
-}
-
 pub fn player_debug_system(
     input: Res<ButtonInput<KeyCode>>,
     mut player_components_query: Query<&mut PlayerHealth, With<Player>>,
@@ -206,34 +205,59 @@ pub fn player_debug_system(
     }
 }
 
-pub fn setup_player_sprites(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn load_character_frames(
+    asset_server: &AssetServer,
+    animation: &CharacterAnimationSet,
+    flipped_direction: PlayerFacingDirection,
+) -> HashMap<PlayerFacingDirection, Vec<Handle<Image>>> {
     let mut player_frames: HashMap<PlayerFacingDirection, Vec<Handle<Image>>> = HashMap::new();
 
     for direction in [
         PlayerFacingDirection::Down,
         PlayerFacingDirection::Up,
-        PlayerFacingDirection::Right,
+        animation.side_faces,
     ] {
-        let player_base = match direction {
-            PlayerFacingDirection::Down => "476",
-            PlayerFacingDirection::Right => "479",
-            PlayerFacingDirection::Up => "482",
-            PlayerFacingDirection::Left => continue,
-        };
-
         let frames_vec: Vec<Handle<Image>> = (0..3)
-            .map(|i| {
-            let base = player_base.parse::<i32>().unwrap()+i;
-            let next = base+1;
-            let animation_path = format!("zombie_apocalypse_tileset/organized_separated_sprites/Player Character Walking Animation Frames/Zombie-Tileset---_0{}_Capa-{}.png", base,next);
-            asset_server.load(animation_path)
-        }).collect();
+            .map(|i| asset_server.load(animation.frame_path(animation.first_frame(direction) + i)))
+            .collect();
         player_frames.insert(direction, frames_vec);
     }
-    if let Some(left_frames) = player_frames.get(&PlayerFacingDirection::Right) {
-        player_frames.insert(PlayerFacingDirection::Left, left_frames.clone());
+
+    if let Some(side_frames) = player_frames.get(&animation.side_faces) {
+        player_frames.insert(flipped_direction, side_frames.clone());
+    }
+    player_frames
+}
+
+pub fn setup_player_sprites(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    roster: Res<CharacterRoster>,
+) {
+    let mut character_frames: HashMap<String, CharacterAnimationFrames> = HashMap::new();
+
+    for character in roster.characters.iter() {
+        let flipped_direction = match character.animation.side_faces {
+            PlayerFacingDirection::Left => PlayerFacingDirection::Right,
+            _ => PlayerFacingDirection::Left,
+        };
+        let frames = load_character_frames(&asset_server, &character.animation, flipped_direction);
+        let damaged_frames = character
+            .damaged_animation
+            .as_ref()
+            .map(|animation| load_character_frames(&asset_server, animation, flipped_direction))
+            .unwrap_or_default();
+
+        character_frames.insert(
+            character.id.clone(),
+            CharacterAnimationFrames {
+                frames,
+                damaged_frames,
+                flipped_direction,
+            },
+        );
     }
-    commands.insert_resource(PlayerAnimationFrames(player_frames));
+    commands.insert_resource(PlayerAnimationFrames(character_frames));
 }
 pub fn player_animation_tick_system(
     mut query: Query<(&mut PlayerAnimationTimer, &mut PlayerAnimationFrame)>,
@@ -257,16 +281,27 @@ pub fn player_movement_animation_system(
             &mut PlayerFacingDirection,
             &mut Sprite,
             &PlayerAnimationFrame,
+            &PlayableCharacter,
+            Option<&HitFlash>,
         ),
         With<Player>,
     >,
     player_animation_frames: Res<PlayerAnimationFrames>,
 ) {
-    let (facing_direction, mut sprite, player_animation_frame) =
-        player_animation_query.single_mut();
+    let Ok((facing_direction, mut sprite, player_animation_frame, character, hit_flash)) =
+        player_animation_query.get_single_mut()
+    else {
+        return;
+    };
 
-    let frames = &player_animation_frames.0;
-    let direction_frames = &frames[&facing_direction];
+    let Some(character_frames) = player_animation_frames.0.get(&character.0) else {
+        warn_once!("No animation frames found for character {}!", character.0);
+        return;
+    };
+    let direction_frames = match character_frames.damaged_frames.get(&facing_direction) {
+        Some(damaged_frames) if hit_flash.is_some() => damaged_frames,
+        _ => &character_frames.frames[&facing_direction],
+    };
     let frame_index = match player_animation_frame.0 {
         0 => 0,
         1 => 1,
@@ -275,7 +310,7 @@ pub fn player_movement_animation_system(
         _ => 0,
     };
     sprite.image = direction_frames[frame_index].clone();
-    sprite.flip_x = *facing_direction == PlayerFacingDirection::Left;
+    sprite.flip_x = *facing_direction == character_frames.flipped_direction;
 }
 
 pub fn set_player_animation_to_start_frame(
@@ -290,12 +325,21 @@ pub fn set_player_animation_to_start_frame(
 pub fn fire_weapon_system(
     mouse_input: Res<ButtonInput<MouseButton>>,
     key_input: Res<ButtonInput<KeyCode>>,
-    mut event_writer: EventWriter<WeaponFiredEvent>,
+    mut trigger: ResMut<WeaponTrigger>,
     cursor_position: Res<WorldMouseCoordinates>,
 ) {
-    if mouse_input.just_pressed(MouseButton::Left) || key_input.just_pressed(KeyCode::Enter)  {
-        event_writer.send(WeaponFiredEvent(cursor_position.0));
-    }
+    trigger.held = mouse_input.pressed(MouseButton::Left) || key_input.pressed(KeyCode::Enter);
+    trigger.just_pressed =
+        mouse_input.just_pressed(MouseButton::Left) || key_input.just_pressed(KeyCode::Enter);
+    trigger.target = cursor_position.0;
+}
+
+/// Lets go of the trigger while the inventory screen is open.
+pub fn release_trigger_system(mut trigger: ResMut<WeaponTrigger>) {
+    *trigger = WeaponTrigger {
+        target: trigger.target,
+        ..default()
+    };
 }
 
 pub fn cursor_system(
@@ -315,14 +359,35 @@ pub fn cursor_system(
     }
 }
 
+pub fn player_aim_system(
+    cursor_position: Res<WorldMouseCoordinates>,
+    mut player_query: Query<(&Transform, &mut PlayerAim, &mut PlayerFacingDirection), With<Player>>,
+) {
+    let Ok((transform, mut aim, mut facing_direction)) = player_query.get_single_mut() else {
+        return;
+    };
+
+    let aim_direction = cursor_position.0 - transform.translation.truncate();
+    if aim_direction.length_squared() < f32::EPSILON {
+        return;
+    }
+    aim.0 = aim_direction.normalize();
+
+    let new_facing_direction = PlayerFacingDirection::from_direction(aim.0);
+    if *facing_direction != new_facing_direction {
+        *facing_direction = new_facing_direction;
+    }
+}
+
 pub fn player_enemy_collision_damage_system(
     mut commands: Commands,
     time: Res<Time>,
-    enemy_query: Query<Entity, With<Enemy>>,
-    mut player_query: Query<(Entity, &mut PlayerHealth, Option<&mut DamageInvulnerability>), With<Player>>,
+    enemy_query: Query<(&Transform, &ContactDamage), With<Enemy>>,
+    mut player_query: Query<(Entity, &Transform, &mut PlayerHealth, Option<&mut DamageInvulnerability>, Option<&PassiveAbility>), With<Player>>,
     mut collision_events: EventReader<Collision>,
+    mut hit_events: EventWriter<HitEvent>,
 ) {
-    let Ok((player_entity, mut player_health, invulnerability)) = player_query.get_single_mut() else { return };
+    let Ok((player_entity, player_transform, mut player_health, invulnerability, passive)) = player_query.get_single_mut() else { return };
 
     if let Some(mut invulnerability) = invulnerability {
         invulnerability.timer.tick(time.delta());
@@ -336,12 +401,24 @@ pub fn player_enemy_collision_damage_system(
         let entity1 = collision.0.entity1;
         let entity2 = collision.0.entity2;
 
-        let is_enemy_collision = (entity1 == player_entity && enemy_query.get(entity2).is_ok()) ||
-            (entity2 == player_entity && enemy_query.get(entity1).is_ok());
+        let enemy = if entity1 == player_entity {
+            enemy_query.get(entity2)
+        } else if entity2 == player_entity {
+            enemy_query.get(entity1)
+        } else {
+            continue;
+        };
 
-        if is_enemy_collision {
-            player_health.decrease_health(1);
-            commands.entity(player_entity).insert(DamageInvulnerability::default());
+        if let Ok((enemy_transform, contact_damage)) = enemy {
+            player_health.decrease_health(contact_damage.0);
+            hit_events.send(HitEvent {
+                target: player_entity,
+                direction: (player_transform.translation - enemy_transform.translation).truncate(),
+                force: 1.0,
+            });
+            commands
+                .entity(player_entity)
+                .insert(PassiveAbility::damage_invulnerability(passive));
             break;
         }
     }
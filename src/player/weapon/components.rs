@@ -0,0 +1,155 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::{inventory::items::ItemId, status_effects::components::StatusEffect};
+
+use super::resources::WeaponDefinition;
+
+/// The weapon currently held by the player, `item` is the key into the `WeaponRegistry`.
+#[derive(Component)]
+pub struct Weapon {
+    pub item: ItemId,
+}
+
+/// Trigger bookkeeping of the held weapon.
+#[derive(Component, Debug, Default)]
+pub struct WeaponState {
+    /// Seconds until the next shot can leave the barrel. Overshoot below zero is carried
+    /// into the next shot so the fire rate does not depend on the frame rate.
+    pub cooldown: f32,
+    /// Seconds a click made during the cooldown stays queued before it is dropped.
+    pub buffered_input: f32,
+    /// Shots left in the burst that is currently being fired.
+    pub burst_remaining: u32,
+}
+
+/// Rounds loaded in each weapon the player owns. Lives on the player so magazines
+/// keep their state while a weapon is holstered.
+#[derive(Component, Debug, Default)]
+pub struct WeaponMagazines(HashMap<ItemId, u32>);
+
+impl WeaponMagazines {
+    /// Weapons that were never fired start with a full magazine.
+    pub fn loaded(&self, weapon: &WeaponDefinition) -> u32 {
+        self.0
+            .get(&weapon.item)
+            .copied()
+            .unwrap_or(weapon.magazine_size)
+    }
+
+    pub fn set_loaded(&mut self, item: ItemId, rounds: u32) {
+        self.0.insert(item, rounds);
+    }
+}
+
+/// Present on the weapon while it is being reloaded. Swapping the weapon or firing
+/// the rounds still in the magazine cancels the reload.
+#[derive(Component)]
+pub struct Reloading(pub Timer);
+
+/// Present for a moment after pulling the trigger on an empty magazine.
+#[derive(Component)]
+pub struct EmptyClick(pub Timer);
+
+/// Position of the barrel tip relative to the weapon sprite's center, in the weapon's local space.
+#[derive(Component)]
+pub struct Muzzle(pub Vec2);
+
+#[derive(Component)]
+pub struct Projectile {
+    pub damage: f32,
+    pub critical: bool,
+    /// Multiplier on how far hit targets are pushed back.
+    pub knockback: f32,
+    /// Entity that fired the projectile, it never hits its owner.
+    pub owner: Entity,
+    pub status_effect: Option<StatusEffect>,
+}
+
+/// Scales damage down from full at `start_distance` to `min_multiplier` at `end_distance`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct DamageFalloff {
+    pub start_distance: f32,
+    pub end_distance: f32,
+    pub min_multiplier: f32,
+}
+
+impl Default for DamageFalloff {
+    /// No falloff at all.
+    fn default() -> Self {
+        Self {
+            start_distance: f32::MAX,
+            end_distance: f32::MAX,
+            min_multiplier: 1.0,
+        }
+    }
+}
+
+impl DamageFalloff {
+    pub fn multiplier(&self, distance: f32) -> f32 {
+        if distance <= self.start_distance {
+            return 1.0;
+        }
+        let falloff_range = (self.end_distance - self.start_distance).max(f32::EPSILON);
+        let progress = ((distance - self.start_distance) / falloff_range).min(1.0);
+        (1.0 - progress) + self.min_multiplier * progress
+    }
+}
+
+/// Removes the projectile once the timer runs out.
+#[derive(Component)]
+pub struct ProjectileLifetime(pub Timer);
+
+/// Everything the projectile already hit, so each enemy only takes damage once.
+#[derive(Component, Default)]
+pub struct ProjectileHits(pub Vec<Entity>);
+
+/// Enemies the projectile can still pass through.
+#[derive(Component)]
+pub struct Pierce(pub u32);
+
+/// Bounces off obstacles the projectile has left.
+#[derive(Component)]
+pub struct Ricochet(pub u32);
+
+/// Animation spawned wherever the projectile hits something.
+#[derive(Component, Clone)]
+pub struct ImpactEffect {
+    pub frames: Vec<Handle<Image>>,
+    pub frame_seconds: f32,
+}
+
+/// Plays its frames once and despawns.
+#[derive(Component)]
+pub struct EffectAnimation {
+    pub frames: Vec<Handle<Image>>,
+    pub frame: usize,
+    pub timer: Timer,
+}
+
+/// Removes the projectile once it travelled `max_distance` away from `origin`.
+#[derive(Component)]
+pub struct ProjectileRange {
+    pub origin: Vec2,
+    pub max_distance: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_damage_falloff_multiplier() {
+        let falloff = DamageFalloff {
+            start_distance: 100.0,
+            end_distance: 200.0,
+            min_multiplier: 0.2,
+        };
+
+        assert_eq!(falloff.multiplier(50.0), 1.0);
+        assert!((falloff.multiplier(150.0) - 0.6).abs() < 1e-5);
+        assert_eq!(falloff.multiplier(500.0), 0.2);
+        assert_eq!(DamageFalloff::default().multiplier(10_000.0), 1.0);
+    }
+}
@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+use crate::inventory::items::ItemId;
+
+#[derive(Event)]
+pub struct WeaponFiredEvent(pub Vec2);
+
+/// Sent for every shot that actually left the barrel, unlike `WeaponFiredEvent` which is
+/// also sent for clicks on an empty magazine.
+#[derive(Event)]
+pub struct ShotFiredEvent {
+    pub weapon: ItemId,
+}
@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use events::{ShotFiredEvent, WeaponFiredEvent};
+use pool::{prewarm_pools_system, EffectPool, ProjectilePool};
+use resources::{WeaponRegistry, WeaponTrigger};
+use systems::{
+    effect_animation_system, empty_click_system, equip_selected_weapon_system,
+    load_weapon_assets, projectile_collision_system, projectile_expiry_system,
+    reload_progress_system, spawn_projectile_component, start_reload_system, weapon_aim_system,
+    weapon_fire_control_system,
+};
+
+use crate::AppState;
+
+use super::{fire_weapon_system, player_aim_system};
+
+pub mod components;
+pub mod events;
+pub mod pool;
+pub mod resources;
+mod systems;
+
+pub(crate) use systems::load_effect;
+
+pub struct WeaponPlugin;
+
+impl Plugin for WeaponPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<WeaponFiredEvent>()
+            .add_event::<ShotFiredEvent>();
+        app.insert_resource(WeaponRegistry::load());
+        app.init_resource::<WeaponTrigger>()
+            .init_resource::<ProjectilePool>()
+            .init_resource::<EffectPool>();
+        app.add_systems(Startup, (load_weapon_assets, prewarm_pools_system));
+        app.add_systems(
+            Update,
+            (equip_selected_weapon_system, weapon_aim_system)
+                .chain()
+                .after(player_aim_system)
+                .run_if(in_state(AppState::InGame)),
+        );
+        app.add_systems(
+            Update,
+            (
+                weapon_fire_control_system,
+                start_reload_system,
+                reload_progress_system,
+                spawn_projectile_component,
+                empty_click_system,
+            )
+                .chain()
+                .after(fire_weapon_system)
+                .run_if(in_state(AppState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (
+                projectile_collision_system,
+                projectile_expiry_system,
+                effect_animation_system,
+            )
+                .chain()
+                .run_if(in_state(AppState::InGame)),
+        );
+
+        #[cfg(debug_assertions)]
+        app.add_systems(
+            Update,
+            (
+                crate::pooling::log_pool_metrics_system::<components::Projectile>,
+                crate::pooling::log_pool_metrics_system::<components::EffectAnimation>,
+            )
+                .run_if(bevy::time::common_conditions::on_timer(
+                    std::time::Duration::from_secs(10),
+                ))
+                .run_if(in_state(AppState::InGame)),
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use avian2d::prelude::{
+    Collider, CollisionLayers, LinearVelocity, LockedAxes, RigidBody, Sensor, SweptCcd,
+};
+use bevy::prelude::*;
+
+use crate::{
+    collision::GameLayer,
+    pooling::{components::Inactive, resources::EntityPool},
+};
+
+use super::components::{EffectAnimation, Projectile};
+
+pub type ProjectilePool = EntityPool<Projectile>;
+pub type EffectPool = EntityPool<EffectAnimation>;
+
+pub const PROJECTILE_POOL_PREWARM: usize = 64;
+pub const EFFECT_POOL_PREWARM: usize = 32;
+
+/// Physics and rendering components every projectile has, parked and switched off.
+pub fn inactive_projectile_bundle() -> impl Bundle {
+    (
+        Inactive,
+        Sprite::default(),
+        Transform::default(),
+        Visibility::Hidden,
+        RigidBody::Dynamic,
+        Collider::round_rectangle(4.0, 1.0, 0.5),
+        // Projectiles pass through what they hit and resolve hits themselves.
+        Sensor,
+        LockedAxes::ROTATION_LOCKED,
+        LinearVelocity::ZERO,
+        CollisionLayers::NONE,
+        SweptCcd::default(),
+    )
+}
+
+/// Takes a projectile from the pool, or spawns one if the pool is empty, and sends it flying.
+/// `gameplay` holds the `Projectile` and the components describing its behaviour.
+pub fn launch_projectile(
+    commands: &mut Commands,
+    pool: &mut ProjectilePool,
+    gameplay: impl Bundle,
+    sprite: Sprite,
+    transform: Transform,
+    velocity: Vec2,
+) -> Entity {
+    let entity = pool.acquire().unwrap_or_else(|| {
+        pool.track_spawned();
+        commands.spawn(inactive_projectile_bundle()).id()
+    });
+    commands.entity(entity).remove::<Inactive>().insert((
+        gameplay,
+        sprite,
+        transform,
+        Visibility::Inherited,
+        LinearVelocity(velocity),
+        CollisionLayers::new(
+            GameLayer::PROJECTILE,
+            [GameLayer::ENEMY, GameLayer::TERRAIN],
+        ),
+    ));
+    entity
+}
+
+pub fn release_projectile(commands: &mut Commands, pool: &mut ProjectilePool, entity: Entity) {
+    commands.entity(entity).insert((
+        Inactive,
+        Visibility::Hidden,
+        LinearVelocity::ZERO,
+        CollisionLayers::NONE,
+    ));
+    pool.release(entity);
+}
+
+pub fn inactive_effect_bundle() -> impl Bundle {
+    (
+        Inactive,
+        EffectAnimation {
+            frames: Vec::new(),
+            frame: 0,
+            timer: Timer::default(),
+        },
+        Sprite::default(),
+        Transform::default(),
+        Visibility::Hidden,
+    )
+}
+
+pub fn play_effect(
+    commands: &mut Commands,
+    pool: &mut EffectPool,
+    frames: &[Handle<Image>],
+    frame_seconds: f32,
+    transform: Transform,
+) {
+    let Some(first_frame) = frames.first() else {
+        return;
+    };
+    let entity = pool.acquire().unwrap_or_else(|| {
+        pool.track_spawned();
+        commands.spawn(inactive_effect_bundle()).id()
+    });
+    commands.entity(entity).remove::<Inactive>().insert((
+        EffectAnimation {
+            frames: frames.to_vec(),
+            frame: 0,
+            timer: Timer::from_seconds(frame_seconds, TimerMode::Repeating),
+        },
+        Sprite {
+            image: first_frame.clone(),
+            ..default()
+        },
+        transform,
+        Visibility::Inherited,
+    ));
+}
+
+pub fn release_effect(commands: &mut Commands, pool: &mut EffectPool, entity: Entity) {
+    commands
+        .entity(entity)
+        .insert((Inactive, Visibility::Hidden));
+    pool.release(entity);
+}
+
+pub fn prewarm_pools_system(
+    mut commands: Commands,
+    mut projectile_pool: ResMut<ProjectilePool>,
+    mut effect_pool: ResMut<EffectPool>,
+) {
+    for _ in 0..PROJECTILE_POOL_PREWARM {
+        projectile_pool.prewarm(commands.spawn(inactive_projectile_bundle()).id());
+    }
+    for _ in 0..EFFECT_POOL_PREWARM {
+        effect_pool.prewarm(commands.spawn(inactive_effect_bundle()).id());
+    }
+}
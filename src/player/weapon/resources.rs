@@ -0,0 +1,326 @@
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::{
+    inventory::{components::Inventory, items::ItemId},
+    sprites::SPRITE_FOLDER,
+    status_effects::components::StatusEffect,
+};
+
+use super::components::{DamageFalloff, ImpactEffect};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize)]
+pub enum FireMode {
+    /// One shot per click.
+    #[default]
+    SemiAuto,
+    /// Keeps firing while the trigger is held.
+    FullAuto,
+    /// Fires `count` shots `interval_seconds` apart per click.
+    Burst { count: u32, interval_seconds: f32 },
+}
+
+/// Short sprite animation, like the spark where a projectile hits or a muzzle flash.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EffectDefinition {
+    /// Sprite paths relative to organized_separated_sprites.
+    pub frames: Vec<String>,
+    pub frame_seconds: f32,
+}
+
+impl EffectDefinition {
+    pub fn frame_paths(&self) -> impl Iterator<Item = String> + '_ {
+        self.frames
+            .iter()
+            .map(|frame| format!("{}/{}", SPRITE_FOLDER, frame))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProjectileSettings {
+    /// Seconds before a projectile that hit nothing is removed.
+    pub lifetime_seconds: f32,
+    /// Enemies a projectile passes through before the one that stops it.
+    pub pierce: u32,
+    /// Times a projectile bounces off obstacles before it is removed.
+    pub ricochets: u32,
+    pub impact_effect: Option<EffectDefinition>,
+    /// Damage drops with the distance travelled.
+    #[serde(default)]
+    pub damage_falloff: Option<DamageFalloff>,
+    /// Scales how far hit enemies are pushed back.
+    #[serde(default = "default_knockback")]
+    pub knockback: f32,
+    /// Effect applied to every enemy the projectile hits.
+    #[serde(default)]
+    pub status_effect: Option<StatusEffect>,
+}
+
+fn default_knockback() -> f32 {
+    1.0
+}
+
+fn default_crit_multiplier() -> f32 {
+    2.0
+}
+
+impl Default for ProjectileSettings {
+    fn default() -> Self {
+        Self {
+            lifetime_seconds: 2.0,
+            pierce: 0,
+            ricochets: 0,
+            impact_effect: None,
+            damage_falloff: None,
+            knockback: default_knockback(),
+            status_effect: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize)]
+pub enum SpreadPattern {
+    /// Every projectile gets a random angle inside the spread cone.
+    #[default]
+    Random,
+    /// Projectiles are fanned out evenly across the spread cone.
+    Even,
+}
+
+impl SpreadPattern {
+    /// Angle offsets in radians of `count` projectiles inside a cone of `spread` radians.
+    pub fn offsets(&self, count: u32, spread: f32, rng: &mut impl Rng) -> Vec<f32> {
+        let half_spread = spread * 0.5;
+        match self {
+            SpreadPattern::Random if half_spread > 0.0 => (0..count)
+                .map(|_| rng.gen_range(-half_spread..=half_spread))
+                .collect(),
+            SpreadPattern::Even if count > 1 => (0..count)
+                .map(|index| -half_spread + spread * index as f32 / (count - 1) as f32)
+                .collect(),
+            _ => vec![0.0; count as usize],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WeaponDefinition {
+    pub item: ItemId,
+    /// Shots per second, or bursts per second for burst weapons.
+    pub fire_rate: f32,
+    #[serde(default)]
+    pub fire_mode: FireMode,
+    /// Full angle of the cone that projectiles are scattered over.
+    pub spread_degrees: f32,
+    #[serde(default)]
+    pub spread_pattern: SpreadPattern,
+    pub projectile_count: u32,
+    pub projectile_speed: f32,
+    pub damage: f32,
+    /// Chance from 0 to 1 that a projectile is a critical hit.
+    #[serde(default)]
+    pub crit_chance: f32,
+    /// Damage multiplier of critical hits.
+    #[serde(default = "default_crit_multiplier")]
+    pub crit_multiplier: f32,
+    pub range: f32,
+    #[serde(default)]
+    pub projectile: ProjectileSettings,
+    pub magazine_size: u32,
+    pub reload_seconds: f32,
+    /// Item that reloads draw from, `None` means unlimited reserve ammo.
+    #[serde(default)]
+    pub ammo_item: Option<ItemId>,
+    /// Start reloading when the trigger is pulled on an empty magazine.
+    #[serde(default)]
+    pub auto_reload: bool,
+    /// Work the pump between shots, pulling the weapon back while it cycles.
+    #[serde(default)]
+    pub pump_action: bool,
+    #[serde(default)]
+    pub muzzle_flash: Option<EffectDefinition>,
+    pub sprite: String,
+    pub projectile_sprite: String,
+    pub muzzle: (f32, f32),
+}
+
+impl WeaponDefinition {
+    /// Seconds from the last shot of a trigger pull until the weapon fires again. Burst weapons
+    /// spend part of their period on the shots of the burst.
+    pub fn cooldown_seconds(&self) -> f32 {
+        let period = 1.0 / self.fire_rate.max(f32::EPSILON);
+        match self.fire_mode {
+            FireMode::Burst {
+                count,
+                interval_seconds,
+            } => (period - count.saturating_sub(1) as f32 * interval_seconds).max(0.0),
+            _ => period,
+        }
+    }
+
+    /// Damage dealt per second of holding the trigger, ignoring crits, reloads and misses.
+    pub fn damage_per_second(&self) -> f32 {
+        let shots_per_trigger = match self.fire_mode {
+            FireMode::Burst { count, .. } => count.max(1),
+            _ => 1,
+        };
+        self.damage * (self.projectile_count * shots_per_trigger) as f32 * self.fire_rate
+    }
+
+    /// Ammo left outside the magazine, `None` when the reserve is unlimited.
+    pub fn reserve_ammo(&self, inventory: &Inventory) -> Option<u32> {
+        self.ammo_item.map(|item| inventory.count(item))
+    }
+
+    /// Rolls whether a projectile is a critical hit and returns its damage.
+    pub fn roll_damage(&self, rng: &mut impl Rng) -> (f32, bool) {
+        let critical = rng.gen_bool(self.crit_chance.clamp(0.0, 1.0) as f64);
+        if critical {
+            (self.damage * self.crit_multiplier, true)
+        } else {
+            (self.damage, false)
+        }
+    }
+
+    pub fn sprite_path(&self) -> String {
+        format!("{}/{}", SPRITE_FOLDER, self.sprite)
+    }
+
+    pub fn projectile_sprite_path(&self) -> String {
+        format!("{}/{}", SPRITE_FOLDER, self.projectile_sprite)
+    }
+}
+
+/// Fire input of the current frame, written by the player's input system.
+#[derive(Resource, Debug, Default)]
+pub struct WeaponTrigger {
+    pub held: bool,
+    pub just_pressed: bool,
+    pub target: Vec2,
+}
+
+/// Images of every weapon's projectiles and impact effects, loaded once at startup.
+#[derive(Resource, Default)]
+pub struct WeaponAssets {
+    pub projectile_sprites: HashMap<ItemId, Handle<Image>>,
+    pub impact_effects: HashMap<ItemId, ImpactEffect>,
+    pub muzzle_flashes: HashMap<ItemId, ImpactEffect>,
+}
+
+#[derive(Resource, Debug, Deserialize)]
+pub struct WeaponRegistry {
+    pub weapons: Vec<WeaponDefinition>,
+}
+
+impl WeaponRegistry {
+    pub fn load() -> Self {
+        ron::from_str(include_str!("../../../assets/data/weapons.ron"))
+            .expect("assets/data/weapons.ron should contain valid weapon definitions")
+    }
+
+    pub fn get(&self, item: ItemId) -> Option<&WeaponDefinition> {
+        self.weapons.iter().find(|weapon| weapon.item == item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::weapon::components::WeaponMagazines;
+
+    #[test]
+    fn test_weapon_registry_loads() {
+        let registry = WeaponRegistry::load();
+
+        assert!(registry.get(ItemId::Pistol).is_some());
+        assert!(registry.get(ItemId::Shotgun).is_some());
+        assert!(registry.get(ItemId::Medicine).is_none());
+        for weapon in &registry.weapons {
+            assert!(weapon.fire_rate > 0.0, "{:?} never fires", weapon.item);
+            assert!(weapon.projectile_count > 0, "{:?} has no projectiles", weapon.item);
+            assert!(weapon.magazine_size > 0, "{:?} cannot be loaded", weapon.item);
+            assert!(
+                weapon.projectile.lifetime_seconds > 0.0,
+                "{:?} projectiles vanish instantly",
+                weapon.item
+            );
+        }
+    }
+
+    #[test]
+    fn test_roll_damage_applies_crit_multiplier() {
+        let registry = WeaponRegistry::load();
+        let mut definition = registry.get(ItemId::Pistol).unwrap().clone();
+        let mut rng = rand::thread_rng();
+
+        definition.crit_chance = 1.0;
+        assert_eq!(
+            definition.roll_damage(&mut rng),
+            (definition.damage * definition.crit_multiplier, true)
+        );
+        definition.crit_chance = 0.0;
+        assert_eq!(definition.roll_damage(&mut rng), (definition.damage, false));
+    }
+
+    #[test]
+    fn test_even_spread_fans_out_across_cone() {
+        let mut rng = rand::thread_rng();
+
+        let offsets = SpreadPattern::Even.offsets(3, 1.0, &mut rng);
+
+        assert_eq!(offsets, vec![-0.5, 0.0, 0.5]);
+        assert_eq!(SpreadPattern::Even.offsets(1, 1.0, &mut rng), vec![0.0]);
+    }
+
+    #[test]
+    fn test_random_spread_stays_inside_cone() {
+        let mut rng = rand::thread_rng();
+
+        let offsets = SpreadPattern::Random.offsets(50, 0.4, &mut rng);
+
+        assert_eq!(offsets.len(), 50);
+        assert!(offsets.iter().all(|offset| offset.abs() <= 0.2));
+    }
+
+    #[test]
+    fn test_burst_fire_rate_counts_whole_bursts() {
+        let registry = WeaponRegistry::load();
+        let rifle = registry.get(ItemId::Rifle).unwrap();
+        let FireMode::Burst {
+            count,
+            interval_seconds,
+        } = rifle.fire_mode
+        else {
+            panic!("the rifle fires bursts");
+        };
+
+        let period = (count - 1) as f32 * interval_seconds + rifle.cooldown_seconds();
+        assert!((period - 1.0 / rifle.fire_rate).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_weapon_magazines_start_full() {
+        let registry = WeaponRegistry::load();
+        let pistol = registry.get(ItemId::Pistol).unwrap();
+        let mut magazines = WeaponMagazines::default();
+
+        assert_eq!(magazines.loaded(pistol), pistol.magazine_size);
+
+        magazines.set_loaded(ItemId::Pistol, 0);
+        assert_eq!(magazines.loaded(pistol), 0);
+    }
+
+    #[test]
+    fn test_reserve_ammo_comes_from_inventory() {
+        let registry = WeaponRegistry::load();
+        let pistol = registry.get(ItemId::Pistol).unwrap();
+        let mut inventory = Inventory::with_items(&[(ItemId::Pistol, 1)]);
+
+        assert_eq!(pistol.reserve_ammo(&inventory), Some(0));
+
+        inventory.add_item(ItemId::PistolAmmo, 24);
+        assert_eq!(pistol.reserve_ammo(&inventory), Some(24));
+    }
+}
@@ -0,0 +1,728 @@
+use avian2d::prelude::{Collision, LinearVelocity};
+use bevy::prelude::*;
+
+use crate::{
+    enemy::components::{Enemy, EnemyHealth},
+    explosives::components::ExplosiveBarrel,
+    hit_feedback::events::{DamageEvent, DamageSource, HitEvent},
+    inventory::{components::Inventory, items::ItemId},
+    map_genreation::components::ObstacleTile,
+    player::components::{Player, PlayerAim},
+    pooling::components::Inactive,
+    status_effects::events::ApplyStatusEffectEvent,
+};
+
+use super::{
+    components::{
+        DamageFalloff, EffectAnimation, EmptyClick, ImpactEffect, Muzzle, Pierce, Projectile,
+        ProjectileHits, ProjectileLifetime, ProjectileRange, Reloading, Ricochet, Weapon,
+        WeaponMagazines, WeaponState,
+    },
+    events::{ShotFiredEvent, WeaponFiredEvent},
+    pool::{
+        launch_projectile, play_effect, release_effect, release_projectile, EffectPool,
+        ProjectilePool,
+    },
+    resources::{
+        EffectDefinition, FireMode, WeaponAssets, WeaponDefinition, WeaponRegistry, WeaponTrigger,
+    },
+};
+
+/// Keeps the weapon in the player's hands in sync with the inventory.
+/// Selecting a weapon on the hotbar equips it, selecting any other item keeps the current one.
+pub fn equip_selected_weapon_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    registry: Res<WeaponRegistry>,
+    player_query: Query<(Entity, &Inventory), With<Player>>,
+    weapon_query: Query<(Entity, &Weapon)>,
+) {
+    let Ok((player, inventory)) = player_query.get_single() else {
+        return;
+    };
+    let is_weapon = |item: &ItemId| registry.get(*item).is_some();
+    let equipped = weapon_query.get_single().ok();
+
+    let held_item = equipped
+        .map(|(_, weapon)| weapon.item)
+        .filter(|item| inventory.count(*item) > 0);
+    let wanted_item = inventory
+        .selected_stack()
+        .map(|stack| stack.item)
+        .filter(is_weapon)
+        .or(held_item)
+        .or_else(|| {
+            inventory
+                .slots()
+                .iter()
+                .flatten()
+                .map(|stack| stack.item)
+                .find(is_weapon)
+        });
+
+    if wanted_item == equipped.map(|(_, weapon)| weapon.item) {
+        return;
+    }
+    if let Some((weapon_entity, _)) = equipped {
+        commands.entity(weapon_entity).despawn_recursive();
+    }
+    let Some(definition) = wanted_item.and_then(|item| registry.get(item)) else {
+        return;
+    };
+
+    let weapon_entity = commands
+        .spawn((
+            Weapon {
+                item: definition.item,
+            },
+            WeaponState::default(),
+            Muzzle(Vec2::from(definition.muzzle)),
+            Transform::from_xyz(-6.0, -4.0, 1.0),
+            Sprite {
+                image: asset_server.load(definition.sprite_path()),
+                ..default()
+            },
+            Name::new(format!("Weapon_{:?}", definition.item)),
+        ))
+        .id();
+    commands.entity(player).add_child(weapon_entity);
+    info!("Equipped {}", definition.item.definition().name);
+}
+
+const WEAPON_HOLD_DISTANCE: f32 = 8.0;
+const WEAPON_HOLD_OFFSET: Vec2 = Vec2::new(0.0, -4.0);
+const RELOAD_TILT: f32 = 1.2;
+/// How far a pump action weapon is pulled back while it cycles.
+const PUMP_PULLBACK: f32 = 3.0;
+const EMPTY_CLICK_SECONDS: f32 = 0.3;
+/// How long a click made during the cooldown is remembered.
+const INPUT_BUFFER_SECONDS: f32 = 0.15;
+
+/// Orbits the weapon around the player and points it at the cursor.
+pub fn weapon_aim_system(
+    registry: Res<WeaponRegistry>,
+    player_query: Query<&PlayerAim, With<Player>>,
+    mut weapon_query: Query<(
+        &Weapon,
+        &WeaponState,
+        &mut Transform,
+        &mut Sprite,
+        Option<&Reloading>,
+    )>,
+) {
+    let Ok(aim) = player_query.get_single() else {
+        return;
+    };
+
+    for (weapon, state, mut transform, mut sprite, reloading) in weapon_query.iter_mut() {
+        // Pull pump action weapons back and forth again while the next shell is chambered.
+        let pump_pullback = match registry.get(weapon.item) {
+            Some(definition) if definition.pump_action && state.cooldown > 0.0 => {
+                let progress = 1.0 - state.cooldown / definition.cooldown_seconds();
+                (progress.clamp(0.0, 1.0) * std::f32::consts::PI).sin() * PUMP_PULLBACK
+            }
+            _ => 0.0,
+        };
+        let position = WEAPON_HOLD_OFFSET + aim.0 * (WEAPON_HOLD_DISTANCE - pump_pullback);
+        // Draw the weapon behind the player while aiming upwards.
+        let z = if aim.0.y > 0.5 { -0.5 } else { 1.0 };
+        transform.translation = position.extend(z);
+        // Tip the weapon away from the aim direction and back while reloading.
+        let reload_tilt = reloading.map_or(0.0, |reloading| {
+            let tilt = (reloading.0.fraction() * std::f32::consts::PI).sin() * RELOAD_TILT;
+            if aim.0.x < 0.0 {
+                -tilt
+            } else {
+                tilt
+            }
+        });
+        transform.rotation = Quat::from_rotation_z(aim.0.to_angle() + reload_tilt);
+        // Keep the weapon upright instead of upside down when aiming to the left.
+        sprite.flip_y = aim.0.x < 0.0;
+    }
+}
+
+/// Turns trigger input into `WeaponFiredEvent`s according to the weapon's fire mode and rate.
+pub fn weapon_fire_control_system(
+    time: Res<Time>,
+    trigger: Res<WeaponTrigger>,
+    registry: Res<WeaponRegistry>,
+    mut weapon_query: Query<(&Weapon, &mut WeaponState)>,
+    mut weapon_fired_events: EventWriter<WeaponFiredEvent>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (weapon, mut state) in weapon_query.iter_mut() {
+        let Some(definition) = registry.get(weapon.item) else {
+            continue;
+        };
+
+        state.cooldown -= delta_seconds;
+        state.buffered_input = (state.buffered_input - delta_seconds).max(0.0);
+        if trigger.just_pressed {
+            state.buffered_input = INPUT_BUFFER_SECONDS;
+        }
+
+        while state.cooldown <= 0.0 {
+            let continues_burst = state.burst_remaining > 0;
+            let wants_shot = continues_burst
+                || state.buffered_input > 0.0
+                || (definition.fire_mode == FireMode::FullAuto && trigger.held);
+            if !wants_shot {
+                // Time spent idle does not bank shots.
+                state.cooldown = 0.0;
+                break;
+            }
+
+            if !continues_burst {
+                state.buffered_input = 0.0;
+                if let FireMode::Burst { count, .. } = definition.fire_mode {
+                    state.burst_remaining = count;
+                }
+            }
+            state.cooldown += match definition.fire_mode {
+                FireMode::Burst {
+                    interval_seconds, ..
+                } if state.burst_remaining > 1 => interval_seconds,
+                _ => definition.cooldown_seconds(),
+            };
+            state.burst_remaining = state.burst_remaining.saturating_sub(1);
+            weapon_fired_events.send(WeaponFiredEvent(trigger.target));
+        }
+    }
+}
+
+pub fn start_reload_system(
+    mut commands: Commands,
+    key_input: Res<ButtonInput<KeyCode>>,
+    registry: Res<WeaponRegistry>,
+    player_query: Query<(&Inventory, &WeaponMagazines), With<Player>>,
+    weapon_query: Query<(Entity, &Weapon), Without<Reloading>>,
+) {
+    if !key_input.just_pressed(KeyCode::KeyR) {
+        return;
+    }
+    let Ok((inventory, magazines)) = player_query.get_single() else {
+        return;
+    };
+    let Ok((weapon_entity, weapon)) = weapon_query.get_single() else {
+        return;
+    };
+    let Some(definition) = registry.get(weapon.item) else {
+        return;
+    };
+
+    if can_reload(definition, inventory, magazines) {
+        commands.entity(weapon_entity).insert(Reloading(Timer::from_seconds(
+            definition.reload_seconds,
+            TimerMode::Once,
+        )));
+    }
+}
+
+fn can_reload(
+    definition: &WeaponDefinition,
+    inventory: &Inventory,
+    magazines: &WeaponMagazines,
+) -> bool {
+    magazines.loaded(definition) < definition.magazine_size
+        && definition.reserve_ammo(inventory) != Some(0)
+}
+
+/// Moves ammo from the inventory into the magazine once the reload timer ran out.
+pub fn reload_progress_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    registry: Res<WeaponRegistry>,
+    mut player_query: Query<(&mut Inventory, &mut WeaponMagazines), With<Player>>,
+    mut weapon_query: Query<(Entity, &Weapon, &mut Reloading)>,
+) {
+    let Ok((mut inventory, mut magazines)) = player_query.get_single_mut() else {
+        return;
+    };
+
+    for (weapon_entity, weapon, mut reloading) in weapon_query.iter_mut() {
+        if !reloading.0.tick(time.delta()).finished() {
+            continue;
+        }
+        commands.entity(weapon_entity).remove::<Reloading>();
+        let Some(definition) = registry.get(weapon.item) else {
+            continue;
+        };
+
+        let loaded = magazines.loaded(definition);
+        let missing = definition.magazine_size.saturating_sub(loaded);
+        let taken = match definition.ammo_item {
+            Some(ammo_item) => inventory.remove_item(ammo_item, missing),
+            None => missing,
+        };
+        magazines.set_loaded(weapon.item, loaded + taken);
+    }
+}
+
+pub fn empty_click_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut weapon_query: Query<(Entity, &mut EmptyClick)>,
+) {
+    for (weapon_entity, mut empty_click) in weapon_query.iter_mut() {
+        if empty_click.0.tick(time.delta()).finished() {
+            commands.entity(weapon_entity).remove::<EmptyClick>();
+        }
+    }
+}
+
+pub(crate) fn load_effect(
+    asset_server: &AssetServer,
+    effect: Option<&EffectDefinition>,
+) -> ImpactEffect {
+    match effect {
+        Some(effect) => ImpactEffect {
+            frames: effect
+                .frame_paths()
+                .map(|path| asset_server.load(path))
+                .collect(),
+            frame_seconds: effect.frame_seconds,
+        },
+        None => ImpactEffect {
+            frames: Vec::new(),
+            frame_seconds: 0.0,
+        },
+    }
+}
+
+/// Loads the projectile and effect images of every weapon once, so firing does not have to
+/// go through the asset server.
+pub fn load_weapon_assets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    registry: Res<WeaponRegistry>,
+) {
+    let mut weapon_assets = WeaponAssets::default();
+    for definition in registry.weapons.iter() {
+        weapon_assets.projectile_sprites.insert(
+            definition.item,
+            asset_server.load(definition.projectile_sprite_path()),
+        );
+        weapon_assets.impact_effects.insert(
+            definition.item,
+            load_effect(&asset_server, definition.projectile.impact_effect.as_ref()),
+        );
+        weapon_assets.muzzle_flashes.insert(
+            definition.item,
+            load_effect(&asset_server, definition.muzzle_flash.as_ref()),
+        );
+    }
+    commands.insert_resource(weapon_assets);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_projectile_component(
+    mut commands: Commands,
+    registry: Res<WeaponRegistry>,
+    weapon_assets: Res<WeaponAssets>,
+    mut projectile_pool: ResMut<ProjectilePool>,
+    mut effect_pool: ResMut<EffectPool>,
+    mut weapon_query: Query<(
+        Entity,
+        &Weapon,
+        &GlobalTransform,
+        &Muzzle,
+        &Sprite,
+        Has<Reloading>,
+    )>,
+    mut player_query: Query<(Entity, &Inventory, &mut WeaponMagazines), With<Player>>,
+    mut weapon_fired_event: EventReader<WeaponFiredEvent>,
+    mut shot_fired_events: EventWriter<ShotFiredEvent>,
+) {
+    let Ok((weapon_entity, weapon, transform, muzzle, weapon_sprite, reloading)) =
+        weapon_query.get_single_mut()
+    else {
+        return;
+    };
+    let Ok((player, inventory, mut magazines)) = player_query.get_single_mut() else {
+        return;
+    };
+    let Some(definition) = registry.get(weapon.item) else {
+        return;
+    };
+    let settings = &definition.projectile;
+    let projectile_sprite = weapon_assets.projectile_sprites[&weapon.item].clone();
+    let impact_effect = &weapon_assets.impact_effects[&weapon.item];
+    let muzzle_flash = &weapon_assets.muzzle_flashes[&weapon.item];
+    let damage_falloff = settings.damage_falloff.unwrap_or_default();
+
+    let muzzle_offset = if weapon_sprite.flip_y {
+        Vec2::new(muzzle.0.x, -muzzle.0.y)
+    } else {
+        muzzle.0
+    };
+    let weapon_position = transform.transform_point(muzzle_offset.extend(0.0));
+    let weapon_world_position = weapon_position.truncate();
+    let spread = definition.spread_degrees.to_radians();
+    let mut rng = rand::thread_rng();
+
+    for event in weapon_fired_event.read() {
+        let loaded = magazines.loaded(definition);
+        if loaded == 0 {
+            if reloading {
+                continue;
+            }
+            if definition.auto_reload && can_reload(definition, inventory, &magazines) {
+                commands.entity(weapon_entity).insert(Reloading(Timer::from_seconds(
+                    definition.reload_seconds,
+                    TimerMode::Once,
+                )));
+            } else {
+                commands.entity(weapon_entity).insert(EmptyClick(Timer::from_seconds(
+                    EMPTY_CLICK_SECONDS,
+                    TimerMode::Once,
+                )));
+            }
+            continue;
+        }
+        if reloading {
+            // Firing what is left in the magazine interrupts the reload.
+            commands.entity(weapon_entity).remove::<Reloading>();
+        }
+        magazines.set_loaded(weapon.item, loaded - 1);
+        shot_fired_events.send(ShotFiredEvent {
+            weapon: weapon.item,
+        });
+        play_effect(
+            &mut commands,
+            &mut effect_pool,
+            &muzzle_flash.frames,
+            muzzle_flash.frame_seconds,
+            Transform::from_translation(weapon_position),
+        );
+
+        let target_world_position = event.0;
+        let aim_direction = (target_world_position - weapon_world_position)
+            .try_normalize()
+            .unwrap_or_else(|| transform.right().truncate());
+        let pattern = definition.spread_pattern;
+        for offset in pattern.offsets(definition.projectile_count, spread, &mut rng) {
+            let direction = Vec2::from_angle(offset).rotate(aim_direction);
+            let (damage, critical) = definition.roll_damage(&mut rng);
+            launch_projectile(
+                &mut commands,
+                &mut projectile_pool,
+                (
+                    Projectile {
+                        damage,
+                        critical,
+                        knockback: settings.knockback,
+                        owner: player,
+                        status_effect: settings.status_effect,
+                    },
+                    ProjectileRange {
+                        origin: weapon_world_position,
+                        max_distance: definition.range,
+                    },
+                    ProjectileLifetime(Timer::from_seconds(
+                        settings.lifetime_seconds,
+                        TimerMode::Once,
+                    )),
+                    ProjectileHits::default(),
+                    Pierce(settings.pierce),
+                    Ricochet(settings.ricochets),
+                    damage_falloff,
+                    impact_effect.clone(),
+                ),
+                Sprite {
+                    image: projectile_sprite.clone(),
+                    ..default()
+                },
+                Transform::from_translation(weapon_position)
+                    .with_rotation(Quat::from_rotation_z(direction.to_angle())),
+                direction * definition.projectile_speed,
+            );
+        }
+    }
+}
+
+/// Parks projectiles that flew past their range or outlived their lifetime.
+pub fn projectile_expiry_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut projectile_pool: ResMut<ProjectilePool>,
+    mut projectile_query: Query<
+        (
+            Entity,
+            &Transform,
+            &ProjectileRange,
+            &mut ProjectileLifetime,
+        ),
+        Without<Inactive>,
+    >,
+) {
+    for (entity, transform, range, mut lifetime) in projectile_query.iter_mut() {
+        let out_of_range =
+            transform.translation.truncate().distance(range.origin) > range.max_distance;
+        if lifetime.0.tick(time.delta()).finished() || out_of_range {
+            release_projectile(&mut commands, &mut projectile_pool, entity);
+        }
+    }
+}
+
+pub fn effect_animation_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut effect_pool: ResMut<EffectPool>,
+    mut effect_query: Query<(Entity, &mut EffectAnimation, &mut Sprite), Without<Inactive>>,
+) {
+    for (entity, mut effect, mut sprite) in effect_query.iter_mut() {
+        if !effect.timer.tick(time.delta()).just_finished() {
+            continue;
+        }
+        effect.frame += 1;
+        match effect.frames.get(effect.frame) {
+            Some(frame) => sprite.image = frame.clone(),
+            None => release_effect(&mut commands, &mut effect_pool, entity),
+        }
+    }
+}
+
+/// Normal of the side of an axis aligned square obstacle that `position` is closest to.
+fn obstacle_normal(obstacle_center: Vec2, position: Vec2) -> Vec2 {
+    let offset = position - obstacle_center;
+    if offset.x.abs() > offset.y.abs() {
+        Vec2::new(offset.x.signum(), 0.0)
+    } else {
+        Vec2::new(0.0, offset.y.signum())
+    }
+}
+
+/// Damages enemies, hits barrels and bounces projectiles off obstacles. Projectiles are sensors, so
+/// collisions are reported every frame they overlap something, `ProjectileHits` makes
+/// sure each of them only counts once.
+#[allow(clippy::too_many_arguments)]
+pub fn projectile_collision_system(
+    mut commands: Commands,
+    mut projectile_pool: ResMut<ProjectilePool>,
+    mut effect_pool: ResMut<EffectPool>,
+    mut projectile_query: Query<
+        (
+            &Projectile,
+            &mut LinearVelocity,
+            &mut Transform,
+            &mut ProjectileHits,
+            &mut Pierce,
+            &mut Ricochet,
+            &ProjectileRange,
+            &DamageFalloff,
+            &ImpactEffect,
+        ),
+        Without<Inactive>,
+    >,
+    mut enemy_query: Query<&mut EnemyHealth, With<Enemy>>,
+    obstacle_query: Query<&GlobalTransform, With<ObstacleTile>>,
+    barrel_query: Query<(), With<ExplosiveBarrel>>,
+    mut collision_events: EventReader<Collision>,
+    mut hit_events: EventWriter<HitEvent>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut status_effect_events: EventWriter<ApplyStatusEffectEvent>,
+) {
+    let mut spent_projectiles = Vec::new();
+
+    for collision in collision_events.read() {
+        for (projectile_entity, other_entity) in [
+            (collision.0.entity1, collision.0.entity2),
+            (collision.0.entity2, collision.0.entity1),
+        ] {
+            if spent_projectiles.contains(&projectile_entity) {
+                continue;
+            }
+            let Ok((
+                projectile,
+                mut velocity,
+                mut transform,
+                mut hits,
+                mut pierce,
+                mut ricochet,
+                range,
+                damage_falloff,
+                impact_effect,
+            )) = projectile_query.get_mut(projectile_entity)
+            else {
+                continue;
+            };
+            if other_entity == projectile.owner || hits.0.contains(&other_entity) {
+                continue;
+            }
+
+            if let Ok(mut enemy_health) = enemy_query.get_mut(other_entity) {
+                hits.0.push(other_entity);
+                let travelled = transform.translation.truncate().distance(range.origin);
+                let damage = projectile.damage * damage_falloff.multiplier(travelled);
+                enemy_health.0 -= damage;
+                damage_events.send(DamageEvent {
+                    target: other_entity,
+                    amount: damage,
+                    source: DamageSource::Projectile,
+                    dealer: Some(projectile.owner),
+                    position: transform.translation.truncate(),
+                    critical: projectile.critical,
+                    lethal: enemy_health.0 <= 0.0,
+                });
+                hit_events.send(HitEvent {
+                    target: other_entity,
+                    direction: velocity.0,
+                    force: projectile.knockback,
+                });
+                if let Some(effect) = projectile.status_effect {
+                    status_effect_events.send(ApplyStatusEffectEvent {
+                        target: other_entity,
+                        effect,
+                    });
+                }
+                play_effect(
+                    &mut commands,
+                    &mut effect_pool,
+                    &impact_effect.frames,
+                    impact_effect.frame_seconds,
+                    Transform::from_translation(transform.translation),
+                );
+                if pierce.0 == 0 {
+                    spent_projectiles.push(projectile_entity);
+                    release_projectile(&mut commands, &mut projectile_pool, projectile_entity);
+                } else {
+                    pierce.0 -= 1;
+                }
+            } else if barrel_query.contains(other_entity) {
+                // Barrels stop the projectile, they react to the `HitEvent` themselves.
+                hits.0.push(other_entity);
+                hit_events.send(HitEvent {
+                    target: other_entity,
+                    direction: velocity.0,
+                    force: projectile.knockback,
+                });
+                play_effect(
+                    &mut commands,
+                    &mut effect_pool,
+                    &impact_effect.frames,
+                    impact_effect.frame_seconds,
+                    Transform::from_translation(transform.translation),
+                );
+                spent_projectiles.push(projectile_entity);
+                release_projectile(&mut commands, &mut projectile_pool, projectile_entity);
+            } else if let Ok(obstacle_transform) = obstacle_query.get(other_entity) {
+                hits.0.push(other_entity);
+                play_effect(
+                    &mut commands,
+                    &mut effect_pool,
+                    &impact_effect.frames,
+                    impact_effect.frame_seconds,
+                    Transform::from_translation(transform.translation),
+                );
+                let normal = obstacle_normal(
+                    obstacle_transform.translation().truncate(),
+                    transform.translation.truncate(),
+                );
+                if ricochet.0 == 0 || velocity.0.dot(normal) >= 0.0 {
+                    spent_projectiles.push(projectile_entity);
+                    release_projectile(&mut commands, &mut projectile_pool, projectile_entity);
+                } else {
+                    ricochet.0 -= 1;
+                    velocity.0 = velocity.0.reflect(normal);
+                    transform.rotation = Quat::from_rotation_z(velocity.0.to_angle());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn fire_control_app(item: ItemId) -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<WeaponTrigger>()
+            .insert_resource(WeaponRegistry::load())
+            .add_event::<WeaponFiredEvent>()
+            .add_systems(Update, weapon_fire_control_system);
+        app.world_mut().spawn((Weapon { item }, WeaponState::default()));
+        app
+    }
+
+    /// Advances time by one frame and returns how many shots were fired during it.
+    fn step(app: &mut App, frame_seconds: f32, held: bool, just_pressed: bool) -> usize {
+        let mut trigger = app.world_mut().resource_mut::<WeaponTrigger>();
+        trigger.held = held;
+        trigger.just_pressed = just_pressed;
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(frame_seconds));
+        app.update();
+        app.world_mut()
+            .resource_mut::<Events<WeaponFiredEvent>>()
+            .drain()
+            .count()
+    }
+
+    fn hold_trigger(app: &mut App, frame_seconds: f32, frames: usize) -> usize {
+        (0..frames)
+            .map(|frame| step(app, frame_seconds, true, frame == 0))
+            .sum()
+    }
+
+    #[test]
+    fn test_full_auto_fire_rate_is_framerate_independent() {
+        let mut app_60_fps = fire_control_app(ItemId::Smg);
+        let mut app_20_fps = fire_control_app(ItemId::Smg);
+
+        // 0.95 seconds at 12 shots per second, the first shot leaves right away.
+        let shots_60_fps = hold_trigger(&mut app_60_fps, 1.0 / 60.0, 57);
+        let shots_20_fps = hold_trigger(&mut app_20_fps, 1.0 / 20.0, 19);
+
+        assert_eq!(shots_60_fps, 12);
+        assert_eq!(shots_20_fps, 12);
+    }
+
+    #[test]
+    fn test_semi_auto_fires_once_per_click() {
+        let mut app = fire_control_app(ItemId::Pistol);
+
+        let shots = hold_trigger(&mut app, 1.0 / 60.0, 60);
+
+        assert_eq!(shots, 1);
+    }
+
+    #[test]
+    fn test_click_during_cooldown_is_buffered() {
+        let mut app = fire_control_app(ItemId::Pistol);
+        let frame = 0.04;
+
+        assert_eq!(step(&mut app, frame, true, true), 1);
+        for _ in 0..3 {
+            assert_eq!(step(&mut app, frame, false, false), 0);
+        }
+        // The pistol fires 4 times per second, this click lands 0.05 seconds early.
+        assert_eq!(step(&mut app, frame, true, true), 0);
+        assert_eq!(step(&mut app, frame, false, false), 0);
+        assert_eq!(step(&mut app, frame, false, false), 1);
+        assert_eq!(step(&mut app, frame, false, false), 0);
+    }
+
+    #[test]
+    fn test_obstacle_normal_picks_closest_side() {
+        let center = Vec2::new(100.0, 100.0);
+
+        assert_eq!(obstacle_normal(center, Vec2::new(80.0, 95.0)), Vec2::NEG_X);
+        assert_eq!(obstacle_normal(center, Vec2::new(105.0, 124.0)), Vec2::Y);
+    }
+
+    #[test]
+    fn test_burst_fires_full_burst_per_click() {
+        let mut app = fire_control_app(ItemId::Rifle);
+
+        let shots = hold_trigger(&mut app, 1.0 / 60.0, 20);
+
+        assert_eq!(shots, 3);
+    }
+}
@@ -0,0 +1,6 @@
+use bevy::prelude::Component;
+
+/// Parked pooled entity. It is hidden, does not collide and is skipped by gameplay systems
+/// until it is acquired again.
+#[derive(Component)]
+pub struct Inactive;
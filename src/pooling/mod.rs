@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+pub mod components;
+pub mod resources;
+
+use resources::EntityPool;
+
+/// Logs the metrics of a pool, `T` is the marker type the pool was created with.
+pub fn log_pool_metrics_system<T: Send + Sync + 'static>(pool: Res<EntityPool<T>>) {
+    let pool_name = std::any::type_name::<T>()
+        .rsplit("::")
+        .next()
+        .unwrap_or_default();
+    let metrics = pool.metrics();
+    info!(
+        "{} pool: {} active (peak {}), {} parked, {} spawned, {} reused ({:.0}% reuse)",
+        pool_name,
+        metrics.active,
+        metrics.peak_active,
+        pool.available(),
+        metrics.spawned,
+        metrics.reused,
+        metrics.reuse_ratio() * 100.0
+    );
+}
@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PoolMetrics {
+    /// Entities that had to be spawned, including prewarmed ones.
+    pub spawned: u32,
+    /// Acquisitions served by a parked entity instead of a new spawn.
+    pub reused: u32,
+    pub released: u32,
+    pub active: u32,
+    pub peak_active: u32,
+}
+
+impl PoolMetrics {
+    pub fn reuse_ratio(&self) -> f32 {
+        let acquired = self.reused + self.spawned;
+        if acquired == 0 {
+            0.0
+        } else {
+            self.reused as f32 / acquired as f32
+        }
+    }
+}
+
+/// Free list of parked entities of one kind. `T` only tells pools of different kinds apart.
+#[derive(Resource)]
+pub struct EntityPool<T> {
+    available: Vec<Entity>,
+    metrics: PoolMetrics,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for EntityPool<T> {
+    fn default() -> Self {
+        Self {
+            available: Vec::new(),
+            metrics: PoolMetrics::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> EntityPool<T> {
+    /// Hands out a parked entity. Returns `None` when the pool is empty, the caller then
+    /// spawns a new entity and reports it with `track_spawned`.
+    pub fn acquire(&mut self) -> Option<Entity> {
+        let entity = self.available.pop()?;
+        self.metrics.reused += 1;
+        self.track_active();
+        Some(entity)
+    }
+
+    pub fn track_spawned(&mut self) {
+        self.metrics.spawned += 1;
+        self.track_active();
+    }
+
+    /// Parks a freshly spawned entity without handing it out.
+    pub fn prewarm(&mut self, entity: Entity) {
+        self.metrics.spawned += 1;
+        self.available.push(entity);
+    }
+
+    pub fn release(&mut self, entity: Entity) {
+        self.available.push(entity);
+        self.metrics.released += 1;
+        self.metrics.active = self.metrics.active.saturating_sub(1);
+    }
+
+    pub fn available(&self) -> usize {
+        self.available.len()
+    }
+
+    pub fn metrics(&self) -> PoolMetrics {
+        self.metrics
+    }
+
+    fn track_active(&mut self) {
+        self.metrics.active += 1;
+        self.metrics.peak_active = self.metrics.peak_active.max(self.metrics.active);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestPool;
+
+    #[test]
+    fn test_acquire_reuses_released_entities() {
+        let mut pool = EntityPool::<TestPool>::default();
+        let entity = Entity::from_raw(7);
+
+        assert_eq!(pool.acquire(), None);
+        pool.track_spawned();
+        pool.release(entity);
+
+        assert_eq!(pool.acquire(), Some(entity));
+        let metrics = pool.metrics();
+        assert_eq!(metrics.spawned, 1);
+        assert_eq!(metrics.reused, 1);
+        assert_eq!(metrics.active, 1);
+        assert_eq!(metrics.reuse_ratio(), 0.5);
+    }
+
+    #[test]
+    fn test_prewarmed_entities_are_not_active() {
+        let mut pool = EntityPool::<TestPool>::default();
+
+        pool.prewarm(Entity::from_raw(1));
+        pool.prewarm(Entity::from_raw(2));
+        pool.acquire();
+
+        let metrics = pool.metrics();
+        assert_eq!(pool.available(), 1);
+        assert_eq!(metrics.active, 1);
+        assert_eq!(metrics.peak_active, 1);
+    }
+}
@@ -0,0 +1,13 @@
+/// Folder the zombie apocalypse tileset's sprites are sorted into.
+pub const SPRITE_FOLDER: &str = "zombie_apocalypse_tileset/organized_separated_sprites";
+
+/// Path of a numbered frame in one of the tileset's sprite folders.
+pub fn frame_path(folder: &str, frame: u32) -> String {
+    format!(
+        "{}/{}/Zombie-Tileset---_0{}_Capa-{}.png",
+        SPRITE_FOLDER,
+        folder,
+        frame,
+        frame + 1
+    )
+}
@@ -0,0 +1,207 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum StatusEffectKind {
+    Burning,
+    Slow,
+    Poison,
+    Stun,
+}
+
+/// What happens when an effect is applied to an entity that already has it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+pub enum Stacking {
+    /// Restart the duration if the new one is longer.
+    #[default]
+    Refresh,
+    /// Add the new duration on top of what is left.
+    Extend,
+    /// Add a stack up to `max_stacks` and restart the duration, every stack deals the tick
+    /// damage again.
+    Intensity { max_stacks: u32 },
+    /// Keep the running effect and drop the new one.
+    Ignore,
+}
+
+/// An effect over time, applied by weapons, explosions or enemy attacks.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct StatusEffect {
+    pub kind: StatusEffectKind,
+    pub duration_seconds: f32,
+    /// Damage per tick and stack.
+    #[serde(default)]
+    pub tick_damage: f32,
+    #[serde(default = "default_tick_seconds")]
+    pub tick_seconds: f32,
+    /// Multiplier on movement speed while the effect runs, zero stops movement entirely.
+    #[serde(default = "default_speed_multiplier")]
+    pub speed_multiplier: f32,
+    #[serde(default)]
+    pub stacking: Stacking,
+}
+
+fn default_tick_seconds() -> f32 {
+    0.5
+}
+
+fn default_speed_multiplier() -> f32 {
+    1.0
+}
+
+#[derive(Debug, Clone)]
+pub struct ActiveStatusEffect {
+    pub effect: StatusEffect,
+    pub remaining_seconds: f32,
+    pub stacks: u32,
+    tick: Timer,
+}
+
+impl ActiveStatusEffect {
+    fn new(effect: StatusEffect) -> Self {
+        Self {
+            effect,
+            remaining_seconds: effect.duration_seconds,
+            stacks: 1,
+            tick: Timer::from_seconds(effect.tick_seconds.max(0.01), TimerMode::Repeating),
+        }
+    }
+}
+
+/// Effects currently running on an enemy or the player, at most one entry per kind.
+#[derive(Component, Debug, Default)]
+pub struct StatusEffects {
+    active: Vec<ActiveStatusEffect>,
+}
+
+impl StatusEffects {
+    pub fn apply(&mut self, effect: StatusEffect) {
+        let Some(active) = self
+            .active
+            .iter_mut()
+            .find(|active| active.effect.kind == effect.kind)
+        else {
+            self.active.push(ActiveStatusEffect::new(effect));
+            return;
+        };
+
+        match effect.stacking {
+            Stacking::Refresh => {
+                active.remaining_seconds = active.remaining_seconds.max(effect.duration_seconds);
+            }
+            Stacking::Extend => active.remaining_seconds += effect.duration_seconds,
+            Stacking::Intensity { max_stacks } => {
+                active.stacks = (active.stacks + 1).min(max_stacks.max(1));
+                active.remaining_seconds = effect.duration_seconds;
+            }
+            Stacking::Ignore => {}
+        }
+    }
+
+    /// Advances every effect and returns the damage dealt by ticks during `delta`.
+    /// Effects that ran out are removed.
+    pub fn tick(&mut self, delta: Duration) -> f32 {
+        let mut damage = 0.0;
+        for active in self.active.iter_mut() {
+            active.remaining_seconds -= delta.as_secs_f32();
+            let ticks = active.tick.tick(delta).times_finished_this_tick();
+            damage += ticks as f32 * active.effect.tick_damage * active.stacks as f32;
+        }
+        self.active.retain(|active| active.remaining_seconds > 0.0);
+        damage
+    }
+
+    pub fn speed_multiplier(&self) -> f32 {
+        self.active
+            .iter()
+            .map(|active| active.effect.speed_multiplier)
+            .product()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    pub fn has(&self, kind: StatusEffectKind) -> bool {
+        self.active.iter().any(|active| active.effect.kind == kind)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ActiveStatusEffect> {
+        self.active.iter()
+    }
+}
+
+/// Tick damage the player took that does not add up to a whole heart yet.
+#[derive(Component, Debug, Default)]
+pub struct PendingTickDamage(pub f32);
+
+/// Icon above an entity showing one of its active effects.
+#[derive(Component, Debug)]
+pub struct StatusIndicator {
+    pub kind: StatusEffectKind,
+    pub frame: usize,
+    pub timer: Timer,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn effect(kind: StatusEffectKind, stacking: Stacking) -> StatusEffect {
+        StatusEffect {
+            kind,
+            duration_seconds: 2.0,
+            tick_damage: 5.0,
+            tick_seconds: 0.5,
+            speed_multiplier: 1.0,
+            stacking,
+        }
+    }
+
+    #[test]
+    fn test_intensity_stacks_multiply_tick_damage() {
+        let mut effects = StatusEffects::default();
+        let burning = effect(
+            StatusEffectKind::Burning,
+            Stacking::Intensity { max_stacks: 2 },
+        );
+
+        for _ in 0..3 {
+            effects.apply(burning);
+        }
+        let damage = effects.tick(Duration::from_secs_f32(1.0));
+
+        assert_eq!(damage, 2.0 * 5.0 * 2.0, "two ticks of two stacks");
+    }
+
+    #[test]
+    fn test_stacking_rules_change_duration() {
+        let mut effects = StatusEffects::default();
+        effects.apply(effect(StatusEffectKind::Poison, Stacking::Extend));
+        effects.apply(effect(StatusEffectKind::Poison, Stacking::Extend));
+        effects.apply(effect(StatusEffectKind::Stun, Stacking::Ignore));
+
+        effects.tick(Duration::from_secs_f32(3.0));
+
+        assert!(effects.has(StatusEffectKind::Poison));
+        assert!(!effects.has(StatusEffectKind::Stun));
+    }
+
+    #[test]
+    fn test_speed_multipliers_combine() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusEffect {
+            speed_multiplier: 0.5,
+            ..effect(StatusEffectKind::Slow, Stacking::Refresh)
+        });
+        assert_eq!(effects.speed_multiplier(), 0.5);
+
+        effects.apply(StatusEffect {
+            speed_multiplier: 0.0,
+            ..effect(StatusEffectKind::Stun, Stacking::Refresh)
+        });
+        assert_eq!(effects.speed_multiplier(), 0.0);
+    }
+}
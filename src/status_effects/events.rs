@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+use super::components::StatusEffect;
+
+/// Applies an effect to an enemy or the player, following the effect's stacking rule.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ApplyStatusEffectEvent {
+    pub target: Entity,
+    pub effect: StatusEffect,
+}
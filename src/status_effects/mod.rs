@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use events::ApplyStatusEffectEvent;
+use systems::*;
+
+use crate::AppState;
+
+pub mod components;
+pub mod events;
+pub mod resources;
+mod systems;
+
+/// Effects over time like burning, slow, poison and stun on enemies and the player.
+/// Anything can apply them by sending an `ApplyStatusEffectEvent`.
+pub struct StatusEffectsPlugin;
+
+impl Plugin for StatusEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ApplyStatusEffectEvent>();
+        app.add_systems(Startup, load_status_effect_assets);
+        app.add_systems(
+            Update,
+            (
+                apply_status_effects_system,
+                status_effect_damage_system,
+                status_indicator_system,
+                status_indicator_animation_system,
+            )
+                .chain()
+                .run_if(in_state(AppState::InGame)),
+        );
+    }
+}
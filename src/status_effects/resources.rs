@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+#[derive(Resource, Default)]
+pub struct StatusEffectAssets {
+    /// Flame animation drawn above affected entities, tinted per effect.
+    pub indicator_frames: Vec<Handle<Image>>,
+}
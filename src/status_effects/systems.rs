@@ -0,0 +1,189 @@
+use bevy::prelude::*;
+
+use crate::{
+    enemy::components::{Enemy, EnemyHealth},
+    hit_feedback::events::{DamageEvent, DamageSource},
+    player::components::{Player, PlayerHealth},
+};
+
+use super::{
+    components::{PendingTickDamage, StatusEffectKind, StatusEffects, StatusIndicator},
+    events::ApplyStatusEffectEvent,
+    resources::StatusEffectAssets,
+};
+
+/// Tick damage the player takes before losing a heart.
+const PLAYER_HEALTH_PER_HEART: f32 = 50.0;
+const INDICATOR_SPACING: f32 = 10.0;
+const INDICATOR_HEIGHT: f32 = 22.0;
+const INDICATOR_SCALE: f32 = 0.75;
+const INDICATOR_FRAME_SECONDS: f32 = 0.08;
+
+pub fn load_status_effect_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(StatusEffectAssets {
+        indicator_frames: (0..8)
+            .map(|frame| {
+                asset_server.load(format!(
+                    "projectiles/all_fire_bullet_pixel_16x16_{:02}.png",
+                    frame
+                ))
+            })
+            .collect(),
+    });
+}
+
+pub fn apply_status_effects_system(
+    mut apply_events: EventReader<ApplyStatusEffectEvent>,
+    mut target_query: Query<&mut StatusEffects>,
+) {
+    for event in apply_events.read() {
+        if let Ok(mut effects) = target_query.get_mut(event.target) {
+            effects.apply(event.effect);
+        }
+    }
+}
+
+/// Runs down effect durations and deals their tick damage.
+pub fn status_effect_damage_system(
+    time: Res<Time>,
+    mut enemy_query: Query<
+        (
+            Entity,
+            &mut StatusEffects,
+            &mut EnemyHealth,
+            &GlobalTransform,
+        ),
+        (With<Enemy>, Without<Player>),
+    >,
+    mut player_query: Query<
+        (
+            &mut StatusEffects,
+            &mut PlayerHealth,
+            &mut PendingTickDamage,
+        ),
+        With<Player>,
+    >,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    for (entity, mut effects, mut enemy_health, transform) in enemy_query.iter_mut() {
+        if effects.is_empty() {
+            continue;
+        }
+        let damage = effects.tick(time.delta());
+        if damage <= 0.0 {
+            continue;
+        }
+        enemy_health.0 -= damage;
+        damage_events.send(DamageEvent {
+            target: entity,
+            amount: damage,
+            source: DamageSource::StatusEffect,
+            dealer: None,
+            position: transform.translation().truncate(),
+            critical: false,
+            lethal: enemy_health.0 <= 0.0,
+        });
+    }
+
+    for (mut effects, mut player_health, mut pending_damage) in player_query.iter_mut() {
+        if effects.is_empty() {
+            continue;
+        }
+        pending_damage.0 += effects.tick(time.delta());
+        let hearts = (pending_damage.0 / PLAYER_HEALTH_PER_HEART) as u8;
+        if hearts > 0 {
+            pending_damage.0 -= hearts as f32 * PLAYER_HEALTH_PER_HEART;
+            player_health.decrease_health(hearts);
+        }
+    }
+}
+
+fn indicator_color(kind: StatusEffectKind) -> Color {
+    match kind {
+        StatusEffectKind::Burning => Color::WHITE,
+        StatusEffectKind::Slow => Color::srgb(0.4, 0.7, 1.0),
+        StatusEffectKind::Poison => Color::srgb(0.4, 1.0, 0.3),
+        StatusEffectKind::Stun => Color::srgb(1.0, 1.0, 0.3),
+    }
+}
+
+/// Keeps one indicator above the entity for every effect running on it.
+pub fn status_indicator_system(
+    mut commands: Commands,
+    assets: Res<StatusEffectAssets>,
+    owner_query: Query<(Entity, &StatusEffects, Option<&Children>), Changed<StatusEffects>>,
+    mut indicator_query: Query<(&StatusIndicator, &mut Transform)>,
+) {
+    for (owner, effects, children) in owner_query.iter() {
+        let mut shown = Vec::new();
+        for &child in children.into_iter().flatten() {
+            let Ok((indicator, _)) = indicator_query.get(child) else {
+                continue;
+            };
+            if effects.has(indicator.kind) {
+                shown.push((indicator.kind, child));
+            } else {
+                commands.entity(child).despawn();
+            }
+        }
+
+        let kinds: Vec<StatusEffectKind> =
+            effects.iter().map(|active| active.effect.kind).collect();
+        let left = -(kinds.len().saturating_sub(1) as f32) * INDICATOR_SPACING * 0.5;
+        for (index, kind) in kinds.into_iter().enumerate() {
+            let translation = Vec3::new(
+                left + index as f32 * INDICATOR_SPACING,
+                INDICATOR_HEIGHT,
+                2.0,
+            );
+            match shown.iter().find(|(shown_kind, _)| *shown_kind == kind) {
+                Some((_, indicator)) => {
+                    if let Ok((_, mut transform)) = indicator_query.get_mut(*indicator) {
+                        transform.translation = translation;
+                    }
+                }
+                None => {
+                    let indicator = commands
+                        .spawn((
+                            StatusIndicator {
+                                kind,
+                                frame: 0,
+                                timer: Timer::from_seconds(
+                                    INDICATOR_FRAME_SECONDS,
+                                    TimerMode::Repeating,
+                                ),
+                            },
+                            Sprite {
+                                image: assets.indicator_frames.first().cloned().unwrap_or_default(),
+                                color: indicator_color(kind),
+                                ..default()
+                            },
+                            Transform::from_translation(translation)
+                                .with_scale(Vec3::splat(INDICATOR_SCALE)),
+                            Name::new(format!("StatusIndicator_{:?}", kind)),
+                        ))
+                        .id();
+                    commands.entity(owner).add_child(indicator);
+                }
+            }
+        }
+    }
+}
+
+pub fn status_indicator_animation_system(
+    time: Res<Time>,
+    assets: Res<StatusEffectAssets>,
+    mut indicator_query: Query<(&mut StatusIndicator, &mut Sprite)>,
+) {
+    if assets.indicator_frames.is_empty() {
+        return;
+    }
+
+    for (mut indicator, mut sprite) in indicator_query.iter_mut() {
+        if !indicator.timer.tick(time.delta()).just_finished() {
+            continue;
+        }
+        indicator.frame = (indicator.frame + 1) % assets.indicator_frames.len();
+        sprite.image = assets.indicator_frames[indicator.frame].clone();
+    }
+}
@@ -18,8 +18,32 @@ pub struct ScoreHudContainer;
 #[derive(Component)]
 pub struct KillCountMarker;
 
+#[derive(Component)]
+pub struct WaveHudContainer;
+
+#[derive(Component)]
+pub struct WaveTextMarker;
+
 #[derive(Component)]
 pub struct TimerHudContainer;
 
 #[derive(Component)]
-pub struct TimerTextMarker;
\ No newline at end of file
+pub struct TimerTextMarker;
+
+#[derive(Component)]
+pub struct AmmoTextMarker;
+
+#[derive(Component)]
+pub struct BossHealthBarContainer;
+
+#[derive(Component)]
+pub struct BossHealthBarFill;
+
+#[derive(Component)]
+pub struct BossNameText;
+
+#[derive(Component)]
+pub struct CoinHudContainer;
+
+#[derive(Component)]
+pub struct CoinTextMarker;
@@ -5,7 +5,7 @@ use systems::*;
 mod components;
 mod resources;
 mod systems;
-use crate::{enemy::resources::EnemyKillCount, AppState};
+use crate::{enemy::resources::EnemyKillCount, loot::resources::Coins, AppState};
 
 pub struct HudPlugin;
 
@@ -32,6 +32,29 @@ impl Plugin for HudPlugin {
             .add_systems(
                 Update,
                 update_score_widget_system.run_if(resource_changed::<EnemyKillCount>),
+            )
+            .add_systems(OnEnter(AppState::InGame), spawn_wave_widget_system)
+            .add_systems(OnExit(AppState::InGame), despawn_wave_widget_system)
+            .add_systems(
+                Update,
+                update_wave_widget_system.run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(OnExit(AppState::InGame), despawn_boss_health_bar_system)
+            .add_systems(
+                Update,
+                (spawn_boss_health_bar_system, update_boss_health_bar_system)
+                    .chain()
+                    .run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(OnEnter(AppState::InGame), spawn_coin_widget_system)
+            .add_systems(OnExit(AppState::InGame), despawn_coin_widget_system)
+            .add_systems(
+                Update,
+                update_coin_widget_system.run_if(resource_changed::<Coins>),
+            )
+            .add_systems(
+                Update,
+                update_ammo_widget_system.run_if(in_state(AppState::InGame)),
             );
         app.init_resource::<GameTimer>()
             .add_systems(OnEnter(AppState::InGame), spawn_timer_widget_system)
@@ -1,7 +1,24 @@
 use crate::AppState;
 use crate::{
-    enemy::resources::EnemyKillCount,
-    player::components::{Player, PlayerHealth},
+    enemy::{
+        boss::{
+            components::{Boss, BossIntro},
+            events::BossDefeatedEvent,
+            resources::BossRegistry,
+        },
+        components::EnemyHealth,
+        resources::EnemyKillCount,
+        waves::events::{WaveEndedEvent, WaveStartedEvent},
+    },
+    inventory::components::Inventory,
+    loot::resources::Coins,
+    player::{
+        components::{Player, PlayerHealth},
+        weapon::{
+            components::{EmptyClick, Reloading, Weapon, WeaponMagazines},
+            resources::WeaponRegistry,
+        },
+    },
 };
 
 use super::{components::*, HealthBarAssets};
@@ -28,20 +45,22 @@ pub fn spawn_health_bar_container_system(
                 "Player added with health {}, setting HUD.",
                 player_health.max_health()
             );
-            commands.spawn((
-                Node {
-                    position_type: PositionType::Absolute,
-                    left: Val::Px(10.0),
-                    top: Val::Px(10.0),
-                    display: Display::Flex,
-                    flex_direction: FlexDirection::Row,
-                    column_gap: Val::Px(4.0),
-                    align_items: AlignItems::Center,
-                    ..default()
-                },
-                HealthBarContainer,
-                Name::new("HealthBarContainer"),
-            ));
+            commands
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(10.0),
+                        top: Val::Px(10.0),
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(4.0),
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    HealthBarContainer,
+                    Name::new("HealthBarContainer"),
+                ))
+                .with_children(spawn_ammo_widget);
         }
     };
 }
@@ -227,6 +246,167 @@ pub fn update_score_widget_system(
     }
 }
 
+pub fn spawn_wave_widget_system(mut commands: Commands) {
+    let wave_container = (
+        WaveHudContainer,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(36.0),
+            right: Val::Px(10.0),
+            ..default()
+        },
+        Name::new("WaveHudContainer"),
+    );
+    let wave_text = (WaveTextMarker, Text::new("Get ready"));
+    commands.spawn(wave_container).with_child(wave_text);
+}
+
+pub fn despawn_wave_widget_system(
+    mut commands: Commands,
+    wave_hud_query: Query<Entity, With<WaveHudContainer>>,
+) {
+    if let Ok(wave_hud_entity) = wave_hud_query.get_single() {
+        commands.entity(wave_hud_entity).despawn_recursive();
+    }
+}
+
+pub fn update_wave_widget_system(
+    mut started_events: EventReader<WaveStartedEvent>,
+    mut ended_events: EventReader<WaveEndedEvent>,
+    mut defeated_events: EventReader<BossDefeatedEvent>,
+    mut wave_text_query: Query<&mut Text, With<WaveTextMarker>>,
+) {
+    let label = ended_events
+        .read()
+        .map(|event| {
+            format!(
+                "Wave {} cleared, next in {:.0}s",
+                event.wave, event.rest_seconds
+            )
+        })
+        .chain(
+            started_events
+                .read()
+                .map(|event| format!("Wave {}", event.wave)),
+        )
+        .chain(
+            defeated_events
+                .read()
+                .map(|event| format!("{} defeated", event.name)),
+        )
+        .last();
+    let Some(label) = label else {
+        return;
+    };
+    for mut text in &mut wave_text_query {
+        **text = label.clone();
+    }
+}
+
+const BOSS_BAR_WIDTH: f32 = 400.0;
+const BOSS_BAR_HEIGHT: f32 = 14.0;
+const BOSS_BAR_BACKGROUND: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
+/// Fill color per boss phase, the last one is used for any later phases.
+const BOSS_PHASE_COLORS: [Color; 3] = [
+    Color::srgb(0.8, 0.1, 0.1),
+    Color::srgb(0.9, 0.4, 0.1),
+    Color::srgb(0.6, 0.1, 0.6),
+];
+
+/// Shows a health bar at the bottom of the screen when a boss appears.
+pub fn spawn_boss_health_bar_system(
+    mut commands: Commands,
+    registry: Res<BossRegistry>,
+    boss_query: Query<&Boss, Added<Boss>>,
+    bar_query: Query<(), With<BossHealthBarContainer>>,
+) {
+    let Some(boss) = boss_query.iter().next() else {
+        return;
+    };
+    if !bar_query.is_empty() {
+        return;
+    }
+    let name = registry
+        .get(&boss.id)
+        .map(|definition| definition.name.clone())
+        .unwrap_or_default();
+
+    let bar_container = (
+        BossHealthBarContainer,
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(24.0),
+            left: Val::Px(0.0),
+            right: Val::Px(0.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(4.0),
+            ..default()
+        },
+        Name::new("BossHealthBarContainer"),
+    );
+    let name_text = (
+        BossNameText,
+        Text::new(name),
+        TextFont {
+            font_size: 18.0,
+            ..default()
+        },
+    );
+    let bar_background = (
+        Node {
+            width: Val::Px(BOSS_BAR_WIDTH),
+            height: Val::Px(BOSS_BAR_HEIGHT),
+            ..default()
+        },
+        BackgroundColor(BOSS_BAR_BACKGROUND),
+    );
+    let bar_fill = (
+        BossHealthBarFill,
+        Node {
+            width: Val::Percent(0.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(BOSS_PHASE_COLORS[0]),
+    );
+    commands.spawn(bar_container).with_children(|parent| {
+        parent.spawn(name_text);
+        parent.spawn(bar_background).with_child(bar_fill);
+    });
+}
+
+/// Follows the boss' health, fills up during its entrance and goes away once it is defeated.
+pub fn update_boss_health_bar_system(
+    mut commands: Commands,
+    boss_query: Query<(&Boss, &EnemyHealth, Option<&BossIntro>)>,
+    bar_query: Query<Entity, With<BossHealthBarContainer>>,
+    mut fill_query: Query<(&mut Node, &mut BackgroundColor), With<BossHealthBarFill>>,
+) {
+    let Some((boss, health, intro)) = boss_query.iter().next() else {
+        for bar_entity in bar_query.iter() {
+            commands.entity(bar_entity).despawn_recursive();
+        }
+        return;
+    };
+    let fraction = (health.0 / boss.max_health).clamp(0.0, 1.0)
+        * intro.map_or(1.0, |intro| intro.0.fraction());
+    let color = BOSS_PHASE_COLORS[boss.phase.min(BOSS_PHASE_COLORS.len() - 1)];
+    for (mut node, mut background) in fill_query.iter_mut() {
+        node.width = Val::Percent(fraction * 100.0);
+        background.0 = color;
+    }
+}
+
+pub fn despawn_boss_health_bar_system(
+    mut commands: Commands,
+    bar_query: Query<Entity, With<BossHealthBarContainer>>,
+) {
+    for bar_entity in bar_query.iter() {
+        commands.entity(bar_entity).despawn_recursive();
+    }
+}
+
 pub fn spawn_timer_widget_system(mut commands: Commands) {
     let timer_container = (
         TimerHudContainer,
@@ -278,4 +458,110 @@ pub fn update_timer_system(
     for mut text in &mut timer_query {
         **text = format!("Time: {:.0}:{:02.0}", minutes, seconds);
     }
-}
\ No newline at end of file
+}
+
+pub fn spawn_coin_widget_system(mut commands: Commands, coins: Res<Coins>) {
+    let coin_container = (
+        CoinHudContainer,
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(10.0),
+            top: Val::Px(70.0),
+            ..default()
+        },
+        Name::new("CoinHudContainer"),
+    );
+    let coin_text = (
+        CoinTextMarker,
+        Text::new(format!("Coins: {}", coins.0)),
+        TextFont {
+            font_size: 18.0,
+            ..default()
+        },
+    );
+    commands.spawn(coin_container).with_child(coin_text);
+}
+
+pub fn despawn_coin_widget_system(
+    mut commands: Commands,
+    coin_hud_query: Query<Entity, With<CoinHudContainer>>,
+) {
+    if let Ok(coin_hud_entity) = coin_hud_query.get_single() {
+        commands.entity(coin_hud_entity).despawn_recursive();
+    }
+}
+
+pub fn update_coin_widget_system(
+    coins: Res<Coins>,
+    mut coin_text_query: Query<&mut Text, With<CoinTextMarker>>,
+) {
+    for mut text in &mut coin_text_query {
+        **text = format!("Coins: {}", coins.0);
+    }
+}
+
+const AMMO_COLOR: Color = Color::WHITE;
+const AMMO_EMPTY_COLOR: Color = Color::srgb(0.9, 0.2, 0.2);
+const AMMO_HEART_GAP: f32 = 12.0;
+
+/// Sits right of the last heart, the hearts are added to the container after it.
+fn spawn_ammo_widget(parent: &mut ChildBuilder) {
+    let ammo_container = (
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            margin: UiRect::left(Val::Px(AMMO_HEART_GAP)),
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        Name::new("AmmoHudContainer"),
+    );
+    let ammo_text = (
+        AmmoTextMarker,
+        Text::new(""),
+        TextFont {
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(AMMO_COLOR),
+        TextLayout::new_with_no_wrap(),
+    );
+    parent.spawn(ammo_container).with_child(ammo_text);
+}
+
+pub fn update_ammo_widget_system(
+    registry: Res<WeaponRegistry>,
+    player_query: Query<(&Inventory, &WeaponMagazines), With<Player>>,
+    weapon_query: Query<(&Weapon, Has<Reloading>, Has<EmptyClick>)>,
+    mut ammo_text_query: Query<(&mut Text, &mut TextColor), With<AmmoTextMarker>>,
+) {
+    let Ok((mut text, mut color)) = ammo_text_query.get_single_mut() else {
+        return;
+    };
+    let (Ok((inventory, magazines)), Ok((weapon, reloading, empty_click))) =
+        (player_query.get_single(), weapon_query.get_single())
+    else {
+        text.clear();
+        return;
+    };
+    let Some(definition) = registry.get(weapon.item) else {
+        return;
+    };
+
+    let loaded = magazines.loaded(definition);
+    let reserve = match definition.reserve_ammo(inventory) {
+        Some(reserve) => reserve.to_string(),
+        None => "inf".to_string(),
+    };
+    **text = if reloading {
+        format!("Reloading... / {}", reserve)
+    } else {
+        format!("{} / {}", loaded, reserve)
+    };
+    color.0 = if empty_click || (loaded == 0 && !reloading) {
+        AMMO_EMPTY_COLOR
+    } else {
+        AMMO_COLOR
+    };
+}
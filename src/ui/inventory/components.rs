@@ -0,0 +1,30 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct HotbarContainer;
+
+#[derive(Component)]
+pub struct InventoryScreen;
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct InventorySlot {
+    pub index: usize,
+}
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct InventorySlotWeapon {
+    pub index: usize,
+}
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct InventorySlotHighlight {
+    pub index: usize,
+}
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct InventorySlotCount {
+    pub index: usize,
+}
+
+#[derive(Component)]
+pub struct SelectedItemNameMarker;
@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+use systems::*;
+
+mod components;
+mod resources;
+mod systems;
+use crate::{inventory::InventoryScreenState, AppState};
+
+pub struct InventoryUiPlugin;
+
+impl Plugin for InventoryUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, load_inventory_ui_assets);
+
+        app.add_systems(OnEnter(AppState::InGame), spawn_hotbar_system)
+            .add_systems(OnExit(AppState::InGame), despawn_hotbar_system)
+            .add_systems(OnEnter(InventoryScreenState::Open), spawn_inventory_screen_system)
+            .add_systems(
+                OnExit(InventoryScreenState::Open),
+                despawn_inventory_screen_system,
+            )
+            .add_systems(
+                Update,
+                update_inventory_slots_system.run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(
+                Update,
+                inventory_slot_click_system
+                    .run_if(in_state(AppState::InGame))
+                    .run_if(in_state(InventoryScreenState::Open)),
+            );
+    }
+}
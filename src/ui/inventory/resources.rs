@@ -0,0 +1,13 @@
+use bevy::{asset::Handle, image::Image, prelude::Resource};
+use std::collections::HashMap;
+
+use crate::inventory::items::ItemId;
+
+#[derive(Resource)]
+pub struct InventoryUiAssets {
+    pub empty_slot: Handle<Image>,
+    pub using_slot: Handle<Image>,
+    pub icons: HashMap<ItemId, Handle<Image>>,
+    /// Sprites of the items that are weapons, drawn on top of their slot.
+    pub weapon_sprites: HashMap<ItemId, Handle<Image>>,
+}
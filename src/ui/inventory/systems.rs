@@ -0,0 +1,282 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::{
+    inventory::{
+        components::{Inventory, HOTBAR_SIZE, INVENTORY_SIZE},
+        items::{inventory_sprite_path, ItemId},
+    },
+    player::{components::Player, weapon::resources::WeaponRegistry},
+};
+
+use super::{components::*, resources::InventoryUiAssets};
+
+const SLOT_SIZE: f32 = 48.0;
+const SLOT_WEAPON_WIDTH: f32 = 70.0;
+
+pub fn load_inventory_ui_assets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    weapon_registry: Res<WeaponRegistry>,
+) {
+    let icons: HashMap<ItemId, Handle<Image>> = ItemId::ALL
+        .iter()
+        .map(|item| (*item, asset_server.load(item.icon_path())))
+        .collect();
+    let weapon_sprites: HashMap<ItemId, Handle<Image>> = ItemId::ALL
+        .iter()
+        .filter_map(|item| {
+            let weapon = weapon_registry.get(*item)?;
+            Some((*item, asset_server.load(weapon.sprite_path())))
+        })
+        .collect();
+
+    commands.insert_resource(InventoryUiAssets {
+        empty_slot: asset_server.load(inventory_sprite_path("EMPTY_SLOT.png")),
+        using_slot: asset_server.load(inventory_sprite_path("USING_SLOT.png")),
+        icons,
+        weapon_sprites,
+    });
+}
+
+fn spawn_inventory_slot(parent: &mut ChildBuilder, index: usize, ui_assets: &InventoryUiAssets) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(SLOT_SIZE),
+                height: Val::Px(SLOT_SIZE),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            ImageNode {
+                image: ui_assets.empty_slot.clone(),
+                ..default()
+            },
+            Button,
+            InventorySlot { index },
+            Name::new(format!("InventorySlot_{}", index)),
+        ))
+        .with_children(|slot| {
+            // Only the width is set so the weapon sprite keeps its aspect ratio.
+            slot.spawn((
+                Node {
+                    width: Val::Percent(SLOT_WEAPON_WIDTH),
+                    ..default()
+                },
+                ImageNode::default(),
+                Visibility::Hidden,
+                InventorySlotWeapon { index },
+            ));
+            slot.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                ImageNode {
+                    image: ui_assets.using_slot.clone(),
+                    ..default()
+                },
+                Visibility::Hidden,
+                InventorySlotHighlight { index },
+            ));
+            slot.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(4.0),
+                    bottom: Val::Px(2.0),
+                    ..default()
+                },
+                Text::new(""),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                InventorySlotCount { index },
+            ));
+        });
+}
+
+pub fn spawn_hotbar_system(mut commands: Commands, ui_assets: Res<InventoryUiAssets>) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(10.0),
+                left: Val::Px(0.0),
+                right: Val::Px(0.0),
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Px(4.0),
+                ..default()
+            },
+            HotbarContainer,
+            Name::new("HotbarContainer"),
+        ))
+        .with_children(|parent| {
+            for index in 0..HOTBAR_SIZE {
+                spawn_inventory_slot(parent, index, &ui_assets);
+            }
+        });
+}
+
+pub fn despawn_hotbar_system(
+    mut commands: Commands,
+    hotbar_query: Query<Entity, With<HotbarContainer>>,
+) {
+    if let Ok(hotbar_entity) = hotbar_query.get_single() {
+        commands.entity(hotbar_entity).despawn_recursive();
+    }
+}
+
+pub fn spawn_inventory_screen_system(mut commands: Commands, ui_assets: Res<InventoryUiAssets>) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            GlobalZIndex(10),
+            InventoryScreen,
+            Name::new("InventoryScreen"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Inventory"),
+                TextFont {
+                    font_size: 32.0,
+                    ..default()
+                },
+            ));
+            parent
+                .spawn(Node {
+                    display: Display::Grid,
+                    grid_template_columns: RepeatedGridTrack::px(HOTBAR_SIZE, SLOT_SIZE),
+                    column_gap: Val::Px(4.0),
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                })
+                .with_children(|grid| {
+                    for index in 0..INVENTORY_SIZE {
+                        spawn_inventory_slot(grid, index, &ui_assets);
+                    }
+                });
+            parent.spawn((Text::new(""), SelectedItemNameMarker));
+            parent.spawn((
+                Text::new("[1-5] select  [E] use  [Tab] close  [Click] move to selected slot"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+            ));
+        });
+}
+
+pub fn despawn_inventory_screen_system(
+    mut commands: Commands,
+    screen_query: Query<Entity, With<InventoryScreen>>,
+) {
+    if let Ok(screen_entity) = screen_query.get_single() {
+        commands.entity(screen_entity).despawn_recursive();
+    }
+}
+
+pub fn update_inventory_slots_system(
+    inventory_query: Query<Ref<Inventory>, With<Player>>,
+    ui_assets: Res<InventoryUiAssets>,
+    mut slot_query: Query<(Ref<InventorySlot>, &mut ImageNode)>,
+    mut weapon_query: Query<
+        (&InventorySlotWeapon, &mut ImageNode, &mut Visibility),
+        Without<InventorySlot>,
+    >,
+    mut highlight_query: Query<
+        (&InventorySlotHighlight, &mut Visibility),
+        Without<InventorySlotWeapon>,
+    >,
+    mut count_query: Query<(&InventorySlotCount, &mut Text)>,
+    mut item_name_query: Query<
+        &mut Text,
+        (With<SelectedItemNameMarker>, Without<InventorySlotCount>),
+    >,
+) {
+    let Ok(inventory) = inventory_query.get_single() else {
+        return;
+    };
+    let slots_added = slot_query.iter().any(|(slot, _)| slot.is_added());
+    if !inventory.is_changed() && !slots_added {
+        return;
+    }
+
+    let slots = inventory.slots();
+    for (slot, mut image_node) in slot_query.iter_mut() {
+        image_node.image = match slots.get(slot.index).copied().flatten() {
+            Some(stack) => ui_assets.icons[&stack.item].clone(),
+            None => ui_assets.empty_slot.clone(),
+        };
+    }
+
+    for (weapon, mut image_node, mut visibility) in weapon_query.iter_mut() {
+        let sprite = slots
+            .get(weapon.index)
+            .copied()
+            .flatten()
+            .and_then(|stack| ui_assets.weapon_sprites.get(&stack.item));
+        *visibility = match sprite {
+            Some(sprite) => {
+                image_node.image = sprite.clone();
+                Visibility::Inherited
+            }
+            None => Visibility::Hidden,
+        };
+    }
+
+    for (highlight, mut visibility) in highlight_query.iter_mut() {
+        *visibility = if highlight.index == inventory.selected_slot() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+
+    for (count, mut text) in count_query.iter_mut() {
+        **text = match slots.get(count.index).copied().flatten() {
+            Some(stack) if stack.count > 1 => stack.count.to_string(),
+            _ => String::new(),
+        };
+    }
+
+    for mut text in item_name_query.iter_mut() {
+        **text = inventory
+            .selected_stack()
+            .map(|stack| stack.item.definition().name.to_string())
+            .unwrap_or_default();
+    }
+}
+
+pub fn inventory_slot_click_system(
+    slot_query: Query<(&Interaction, &InventorySlot), Changed<Interaction>>,
+    mut inventory_query: Query<&mut Inventory, With<Player>>,
+) {
+    let Ok(mut inventory) = inventory_query.get_single_mut() else {
+        return;
+    };
+
+    for (interaction, slot) in slot_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if slot.index < HOTBAR_SIZE {
+            inventory.select_hotbar_slot(slot.index);
+        } else {
+            let selected_slot = inventory.selected_slot();
+            inventory.swap_slots(slot.index, selected_slot);
+        }
+    }
+}
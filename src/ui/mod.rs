@@ -1,11 +1,13 @@
 use bevy::prelude::*;
 use hud::HudPlugin;
 use crate::ui::game_over::GameOverScreenPlugin;
+use crate::ui::inventory::InventoryUiPlugin;
 use crate::ui::win_screen::WinScreenPlugin;
 
 mod hud;
 mod win_screen;
 mod game_over;
+mod inventory;
 
 pub struct UiPlugin;
 
@@ -15,6 +17,7 @@ impl Plugin for UiPlugin {
             HudPlugin,
             WinScreenPlugin,
             GameOverScreenPlugin,
+            InventoryUiPlugin,
         ));
     }
 }